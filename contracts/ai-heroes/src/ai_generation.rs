@@ -7,6 +7,9 @@ use shared::*;
 pub trait AIGenerationModule:
     shared::StorageModule
     + shared::EventsModule
+    + crate::hero_management::HeroManagementModule
+    + crate::ai_quota::AIQuotaModule
+    + shared::RandomnessModule
 {
     // AI-powered hero generation
     #[payable("EGLD")]
@@ -20,26 +23,22 @@ pub trait AIGenerationModule:
         self.require_not_paused();
         
         let payment = self.call_value().egld_value().clone_value();
-        let ai_generation_fee = BigUint::from(1_000_000_000_000_000_000u64); // 1 EGLD
-        require!(payment >= ai_generation_fee, shared::ERROR_INSUFFICIENT_FUNDS);
+        require!(payment >= shared::AI_HERO_GENERATION_FEE, shared::ERROR_INSUFFICIENT_FUNDS);
         
         let caller = self.blockchain().get_caller();
         let user_hero_count = self.user_heroes(&caller).len();
         require!(user_hero_count < shared::MAX_HEROES_PER_ACCOUNT as usize, shared::ERROR_MAX_HEROES_REACHED);
+        self.consume_ai_learning_cooldown(&caller);
         
-        // Generate AI request
+        // Generate AI request, the payment stays in escrow until the oracle answers
         let request_id = self.create_ai_request(
             AIRequestType::HeroGeneration,
             &self.build_hero_generation_prompt(&name, &preferred_class, &ai_personality),
+            payment,
         );
         
-        // Create hero with AI-generated stats
-        let hero_id = self.create_hero_from_ai_request(request_id, &caller, name, preferred_class, ai_personality);
-        
-        // Track revenue
-        self.add_revenue(&ManagedBuffer::from(b"ai_hero_generation"), &payment);
-        
-        hero_id
+        // Mint the hero in a pending state until the oracle answers the request
+        self.create_hero_from_ai_request(request_id, &caller, name, preferred_class, ai_personality)
     }
 
     // Traditional hero generation (fallback)
@@ -49,8 +48,7 @@ pub trait AIGenerationModule:
         self.require_not_paused();
         
         let payment = self.call_value().egld_value().clone_value();
-        let basic_generation_fee = BigUint::from(500_000_000_000_000_000u64); // 0.5 EGLD
-        require!(payment >= basic_generation_fee, shared::ERROR_INSUFFICIENT_FUNDS);
+        require!(payment >= shared::BASIC_HERO_GENERATION_FEE, shared::ERROR_INSUFFICIENT_FUNDS);
         
         let caller = self.blockchain().get_caller();
         let user_hero_count = self.user_heroes(&caller).len();
@@ -64,23 +62,135 @@ pub trait AIGenerationModule:
         hero_id
     }
 
-    fn create_ai_request(&self, request_type: AIRequestType, data: &ManagedBuffer) -> u64 {
+    // Oracle Fulfillment
+    #[only_owner]
+    #[endpoint(addAIOracle)]
+    fn add_ai_oracle(&self, oracle: ManagedAddress) {
+        self.ai_oracles().insert(oracle);
+    }
+
+    #[only_owner]
+    #[endpoint(removeAIOracle)]
+    fn remove_ai_oracle(&self, oracle: ManagedAddress) {
+        self.ai_oracles().swap_remove(&oracle);
+    }
+
+    // Deadline for new requests of this type, in seconds after submission
+    #[only_owner]
+    #[endpoint(setAIRequestDeadline)]
+    fn set_ai_request_deadline(&self, request_type: AIRequestType, deadline: u64) {
+        require!(deadline > 0, shared::ERROR_INVALID_AI_DEADLINE);
+        self.ai_request_deadlines(&request_type).set(deadline);
+    }
+
+    #[endpoint(bumpAIRequestRetry)]
+    fn bump_ai_request_retry(&self, request_id: u64) -> u64 {
+        let caller = self.blockchain().get_caller();
+        require!(self.ai_oracles().contains(&caller), shared::ERROR_NOT_AI_ORACLE);
+        
+        let mut ai_request = self.require_open_ai_request(request_id);
+        require!(ai_request.retry_count < shared::MAX_AI_REQUEST_RETRIES, shared::ERROR_AI_RETRY_LIMIT_REACHED);
+        
+        // Once the deadline has passed the requester is owed a refund, so the oracle can no longer extend it
+        let now = self.blockchain().get_block_timestamp();
+        require!(now < ai_request.deadline, shared::ERROR_AI_REQUEST_EXPIRED);
+        
+        ai_request.retry_count += 1;
+        ai_request.deadline = now + self.get_ai_request_deadline(&ai_request.request_type);
+        self.ai_requests(&request_id).set(&ai_request);
+        
+        self.ai_request_retried_event(request_id, ai_request.retry_count, ai_request.deadline);
+        
+        ai_request.deadline
+    }
+
+    // Anyone can close a request the oracle did not answer in time and refund the requester
+    #[endpoint(expireAIRequest)]
+    fn expire_ai_request(&self, request_id: u64) {
+        let mut ai_request = self.require_open_ai_request(request_id);
+        require!(
+            self.blockchain().get_block_timestamp() >= ai_request.deadline,
+            shared::ERROR_AI_REQUEST_NOT_EXPIRED
+        );
+        
+        ai_request.processed = true;
+        self.ai_requests(&request_id).set(&ai_request);
+        self.pending_ai_requests().swap_remove(&request_id);
+        
+        // Pending heroes fall back to the placeholder class stats
+        if ai_request.request_type == AIRequestType::HeroGeneration {
+            self.finalize_ai_hero(request_id, false, &ManagedBuffer::new());
+        }
+        
+        self.ai_request_expired_event(request_id, &ai_request.request_type, &ai_request.requester);
+        self.settle_ai_request_fee(&ai_request, false);
+    }
+
+    #[endpoint(submitAIResponse)]
+    fn submit_ai_response(&self, request_id: u64, success: bool, response: ManagedBuffer) {
+        let gas_before = self.blockchain().get_gas_left();
+        
+        let caller = self.blockchain().get_caller();
+        require!(self.ai_oracles().contains(&caller), shared::ERROR_NOT_AI_ORACLE);
+        
+        let mut ai_request = self.require_open_ai_request(request_id);
+        require!(
+            self.blockchain().get_block_timestamp() < ai_request.deadline,
+            shared::ERROR_AI_REQUEST_EXPIRED
+        );
+        
+        ai_request.processed = true;
+        self.ai_requests(&request_id).set(&ai_request);
+        self.pending_ai_requests().swap_remove(&request_id);
+        self.ai_responses(&request_id).set(&response);
+        
+        match ai_request.request_type {
+            AIRequestType::HeroGeneration => self.finalize_ai_hero(request_id, success, &response),
+            _ => {},
+        }
+        self.settle_ai_request_fee(&ai_request, success);
+        
+        let gas_used = gas_before - self.blockchain().get_gas_left();
+        self.ai_response_received_event(request_id, success, gas_used);
+    }
+
+    // Views
+    #[view(getAIRequestDeadline)]
+    fn get_ai_request_deadline(&self, request_type: &AIRequestType) -> u64 {
+        if !self.ai_request_deadlines(request_type).is_empty() {
+            return self.ai_request_deadlines(request_type).get();
+        }
+        
+        match request_type {
+            AIRequestType::HeroGeneration => shared::AI_HERO_GENERATION_TIMEOUT,
+            _ => shared::AI_REQUEST_DEFAULT_TIMEOUT,
+        }
+    }
+
+    fn create_ai_request(&self, request_type: AIRequestType, data: &ManagedBuffer, fee: BigUint) -> u64 {
+        let caller = self.blockchain().get_caller();
+        self.consume_ai_quota(&caller);
+        
         let request_id = self.ai_request_count().get() + 1;
         self.ai_request_count().set(request_id);
         
+        let timestamp = self.blockchain().get_block_timestamp();
         let ai_request = AIRequest {
             request_id,
-            request_type,
+            request_type: request_type.clone(),
             data: data.clone(),
             callback_address: self.blockchain().get_sc_address(),
-            timestamp: self.blockchain().get_block_timestamp(),
+            timestamp,
             processed: false,
+            requester: caller.clone(),
+            fee,
+            deadline: timestamp + self.get_ai_request_deadline(&request_type),
+            retry_count: 0,
         };
         
         self.ai_requests(&request_id).set(ai_request);
         self.pending_ai_requests().insert(request_id);
         
-        let caller = self.blockchain().get_caller();
         self.user_ai_requests(&caller).insert(request_id);
         
         self.ai_request_submitted_event(&request_id, &request_type, &caller);
@@ -88,6 +198,33 @@ pub trait AIGenerationModule:
         request_id
     }
 
+    fn require_open_ai_request(&self, request_id: u64) -> AIRequest {
+        require!(!self.ai_requests(&request_id).is_empty(), shared::ERROR_AI_REQUEST_NOT_FOUND);
+        
+        let ai_request = self.ai_requests(&request_id).get();
+        require!(!ai_request.processed, shared::ERROR_AI_REQUEST_ALREADY_PROCESSED);
+        
+        ai_request
+    }
+
+    // Delivered requests move the escrowed fee to revenue, undelivered ones refund all of it
+    fn settle_ai_request_fee(&self, ai_request: &AIRequest, delivered: bool) {
+        if ai_request.fee == 0 {
+            return;
+        }
+        
+        if delivered {
+            let fee_type = match ai_request.request_type {
+                AIRequestType::HeroGeneration => ManagedBuffer::from(b"ai_hero_generation"),
+                _ => ManagedBuffer::from(b"ai_services"),
+            };
+            self.add_revenue(&fee_type, &ai_request.fee);
+        } else {
+            self.send().direct_egld(&ai_request.requester, &ai_request.fee);
+            self.ai_request_refunded_event(ai_request.request_id, &ai_request.requester, &ai_request.fee);
+        }
+    }
+
     fn build_hero_generation_prompt(
         &self,
         name: &ManagedBuffer,
@@ -127,23 +264,24 @@ pub trait AIGenerationModule:
         let hero_id = self.hero_count().get() + 1;
         self.hero_count().set(hero_id);
         
-        // Generate AI-enhanced stats (simplified for now - would use AI response in production)
-        let stats = self.generate_ai_enhanced_stats(&preferred_class, &ai_personality);
+        // Placeholder class stats until the oracle delivers the AI result
         let hero_class = preferred_class.unwrap_or(self.determine_class_from_personality(&ai_personality));
+        let stats = self.generate_basic_stats(&hero_class);
         
         // Create AI traits
         let ai_traits = AITraits {
             personality: ai_personality,
             battle_style: self.determine_battle_style(&hero_class),
-            adaptation_rate: self.generate_random_in_range(70, 100),
-            learning_factor: self.generate_random_in_range(60, 90),
-            ai_seed: self.blockchain().get_block_timestamp() + hero_id,
+            adaptation_rate: self.random_in_range(shared::RANDOM_DOMAIN_AI_TRAITS, 70, 100) as u32,
+            learning_factor: self.random_in_range(shared::RANDOM_DOMAIN_AI_TRAITS, 60, 90) as u32,
+            ai_seed: self.random_u64(shared::RANDOM_DOMAIN_AI_SEED),
         };
         
         let hero = Hero {
             id: hero_id,
             name,
             class: hero_class.clone(),
+            rarity: Rarity::AI_Generated,
             level: 1,
             experience: 0,
             stats,
@@ -163,134 +301,54 @@ pub trait AIGenerationModule:
         self.hero_owners(&hero_id).set(owner);
         self.user_heroes(owner).insert(hero_id);
         
-        self.hero_created_event(owner, hero_id, &hero_class, true);
-        
-        hero_id
-    }
-
-    fn create_basic_hero(&self, owner: &ManagedAddress, name: ManagedBuffer, hero_class: HeroClass) -> u64 {
-        let hero_id = self.hero_count().get() + 1;
-        self.hero_count().set(hero_id);
+        self.ai_request_hero(&request_id).set(hero_id);
+        self.pending_ai_heroes(&hero_id).set(request_id);
         
-        let stats = self.generate_basic_stats(&hero_class);
-        
-        let ai_traits = AITraits {
-            personality: ManagedBuffer::from(b"Balanced"),
-            battle_style: BattleStyle::Balanced,
-            adaptation_rate: 50,
-            learning_factor: 50,
-            ai_seed: self.blockchain().get_block_timestamp() + hero_id,
-        };
-        
-        let hero = Hero {
-            id: hero_id,
-            name,
-            class: hero_class.clone(),
-            level: 1,
-            experience: 0,
-            stats,
-            equipment: Equipment {
-                weapon: None,
-                armor: None,
-                helmet: None,
-                boots: None,
-                accessory: None,
-            },
-            ai_traits,
-            creation_timestamp: self.blockchain().get_block_timestamp(),
-            last_evolution: self.blockchain().get_block_timestamp(),
+        // The prompt author earns royalties on every resale of the hero
+        let royalty_rate = if self.creator_royalty_rate().is_empty() {
+            shared::CREATOR_ROYALTY_DEFAULT
+        } else {
+            self.creator_royalty_rate().get()
         };
+        self.hero_creators(&hero_id).set(&HeroCreator {
+            creator: owner.clone(),
+            royalty_rate,
+        });
+        self.creator_heroes(owner).insert(hero_id);
         
-        self.heroes(&hero_id).set(hero);
-        self.hero_owners(&hero_id).set(owner);
-        self.user_heroes(owner).insert(hero_id);
-        
-        self.hero_created_event(owner, hero_id, &hero_class, false);
+        self.hero_created_event(owner, hero_id, &hero_class, true);
         
         hero_id
     }
 
-    fn generate_ai_enhanced_stats(&self, preferred_class: &Option<HeroClass>, personality: &ManagedBuffer) -> HeroStats {
-        // This would integrate with actual AI in production
-        // For now, generate enhanced stats based on class and personality
-        let base_stats = if let Some(class) = preferred_class {
-            self.generate_basic_stats(class)
-        } else {
-            HeroStats {
-                strength: shared::BASE_STAT_POINTS,
-                intelligence: shared::BASE_STAT_POINTS,
-                agility: shared::BASE_STAT_POINTS,
-                vitality: shared::BASE_STAT_POINTS,
-                luck: shared::BASE_STAT_POINTS,
-                magic_power: shared::BASE_STAT_POINTS,
-            }
-        };
-        
-        // Enhance based on personality (simplified)
-        let personality_str = personality.to_boxed_bytes();
-        let enhancement = (personality_str.len() % 20) as u32 + 10;
-        
-        HeroStats {
-            strength: base_stats.strength + enhancement,
-            intelligence: base_stats.intelligence + enhancement,
-            agility: base_stats.agility + enhancement,
-            vitality: base_stats.vitality + enhancement,
-            luck: base_stats.luck + enhancement,
-            magic_power: base_stats.magic_power + enhancement,
+    fn finalize_ai_hero(&self, request_id: u64, success: bool, response: &ManagedBuffer) {
+        let hero_id = self.ai_request_hero(&request_id).get();
+        if self.pending_ai_heroes(&hero_id).is_empty() {
+            return;
         }
-    }
-
-    fn generate_basic_stats(&self, hero_class: &HeroClass) -> HeroStats {
-        match hero_class {
-            HeroClass::Warrior => HeroStats {
-                strength: shared::BASE_STAT_POINTS + 30,
-                intelligence: shared::BASE_STAT_POINTS,
-                agility: shared::BASE_STAT_POINTS + 10,
-                vitality: shared::BASE_STAT_POINTS + 20,
-                luck: shared::BASE_STAT_POINTS,
-                magic_power: shared::BASE_STAT_POINTS - 10,
-            },
-            HeroClass::Mage => HeroStats {
-                strength: shared::BASE_STAT_POINTS - 10,
-                intelligence: shared::BASE_STAT_POINTS + 30,
-                agility: shared::BASE_STAT_POINTS,
-                vitality: shared::BASE_STAT_POINTS,
-                luck: shared::BASE_STAT_POINTS + 10,
-                magic_power: shared::BASE_STAT_POINTS + 20,
-            },
-            HeroClass::Rogue => HeroStats {
-                strength: shared::BASE_STAT_POINTS + 10,
-                intelligence: shared::BASE_STAT_POINTS + 10,
-                agility: shared::BASE_STAT_POINTS + 30,
-                vitality: shared::BASE_STAT_POINTS,
-                luck: shared::BASE_STAT_POINTS + 20,
-                magic_power: shared::BASE_STAT_POINTS - 10,
-            },
-            HeroClass::Paladin => HeroStats {
-                strength: shared::BASE_STAT_POINTS + 20,
-                intelligence: shared::BASE_STAT_POINTS + 10,
-                agility: shared::BASE_STAT_POINTS,
-                vitality: shared::BASE_STAT_POINTS + 20,
-                luck: shared::BASE_STAT_POINTS,
-                magic_power: shared::BASE_STAT_POINTS + 10,
-            },
-            HeroClass::Necromancer => HeroStats {
-                strength: shared::BASE_STAT_POINTS,
-                intelligence: shared::BASE_STAT_POINTS + 25,
-                agility: shared::BASE_STAT_POINTS + 5,
-                vitality: shared::BASE_STAT_POINTS - 5,
-                luck: shared::BASE_STAT_POINTS + 5,
-                magic_power: shared::BASE_STAT_POINTS + 30,
-            },
-            HeroClass::Elementalist => HeroStats {
-                strength: shared::BASE_STAT_POINTS - 5,
-                intelligence: shared::BASE_STAT_POINTS + 20,
-                agility: shared::BASE_STAT_POINTS + 15,
-                vitality: shared::BASE_STAT_POINTS + 5,
-                luck: shared::BASE_STAT_POINTS + 15,
-                magic_power: shared::BASE_STAT_POINTS + 25,
-            },
+        
+        // A failed generation keeps the placeholder class stats
+        if success {
+            require!(response.len() <= shared::MAX_AI_RESPONSE_LENGTH, shared::ERROR_AI_RESPONSE_INVALID);
+            let mut buffer = [0u8; shared::MAX_AI_RESPONSE_LENGTH];
+            let result = decode_hero_generation_response(response.load_to_byte_array(&mut buffer))
+                .unwrap_or_else(|error| sc_panic!(error));
+            
+            let mut hero = self.heroes(&hero_id).get();
+            let old_stats = hero.stats.clone();
+            
+            hero.class = result.class;
+            hero.stats = result.stats;
+            hero.ai_traits.battle_style = result.battle_style;
+            hero.ai_traits.adaptation_rate = result.adaptation_rate;
+            hero.ai_traits.learning_factor = result.learning_factor;
+            self.heroes(&hero_id).set(&hero);
+            
+            self.hero_stats_updated_event(hero_id, &old_stats, &hero.stats);
         }
+        
+        self.pending_ai_heroes(&hero_id).clear();
+        self.ai_hero_finalized_event(hero_id, request_id, success);
     }
 
     fn determine_class_from_personality(&self, personality: &ManagedBuffer) -> HeroClass {
@@ -305,23 +363,6 @@ pub trait AIGenerationModule:
         }
     }
 
-    fn determine_battle_style(&self, hero_class: &HeroClass) -> BattleStyle {
-        match hero_class {
-            HeroClass::Warrior => BattleStyle::Aggressive,
-            HeroClass::Mage => BattleStyle::Tactical,
-            HeroClass::Rogue => BattleStyle::Balanced,
-            HeroClass::Paladin => BattleStyle::Defensive,
-            HeroClass::Necromancer => BattleStyle::Tactical,
-            HeroClass::Elementalist => BattleStyle::Balanced,
-        }
-    }
-
-    fn generate_random_in_range(&self, min: u32, max: u32) -> u32 {
-        let timestamp = self.blockchain().get_block_timestamp();
-        let random_seed = timestamp % (max - min + 1) as u64;
-        min + random_seed as u32
-    }
-
     fn require_not_paused(&self);
     fn add_revenue(&self, fee_type: &ManagedBuffer, amount: &BigUint);
 }
@@ -0,0 +1,82 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait AIQuotaModule:
+    shared::StorageModule
+    + shared::EventsModule
+    + crate::subscriptions::SubscriptionModule
+{
+    // Sliding one-hour window over the caller's recent AI requests
+    fn consume_ai_quota(&self, user: &ManagedAddress) {
+        let now = self.blockchain().get_block_timestamp();
+        let mut request_timestamps = self.ai_request_timestamps(user);
+        
+        while let Some(oldest) = request_timestamps.front() {
+            if oldest + shared::AI_REQUEST_WINDOW > now {
+                break;
+            }
+            request_timestamps.pop_front();
+        }
+        
+        require!(
+            (request_timestamps.len() as u32) < self.ai_request_limit(user),
+            shared::ERROR_AI_QUOTA_EXCEEDED
+        );
+        request_timestamps.push_back(now);
+    }
+
+    fn consume_ai_learning_cooldown(&self, user: &ManagedAddress) {
+        let now = self.blockchain().get_block_timestamp();
+        require!(now >= self.ai_learning_cooldown_expiry(user), shared::ERROR_AI_COOLDOWN_ACTIVE);
+        
+        self.last_ai_learning_request(user).set(now);
+    }
+
+    fn ai_request_limit(&self, user: &ManagedAddress) -> u32 {
+        if self.has_ai_companion_plan(user) {
+            shared::AI_COMPANION_REQUESTS_PER_HOUR
+        } else {
+            shared::MAX_AI_REQUESTS_PER_HOUR
+        }
+    }
+
+    fn ai_learning_cooldown_expiry(&self, user: &ManagedAddress) -> u64 {
+        let last_request = self.last_ai_learning_request(user).get();
+        if last_request == 0 {
+            return 0;
+        }
+        
+        let cooldown = if self.has_ai_companion_plan(user) {
+            shared::AI_COMPANION_LEARNING_COOLDOWN
+        } else {
+            shared::AI_LEARNING_COOLDOWN
+        };
+        last_request + cooldown
+    }
+
+    // Views
+    #[view(getAIQuota)]
+    fn get_ai_quota(&self, user: ManagedAddress) -> MultiValue3<u32, u64, u64> {
+        let now = self.blockchain().get_block_timestamp();
+        let mut requests_in_window = 0u32;
+        let mut window_reset = 0u64;
+        
+        for timestamp in self.ai_request_timestamps(&user).iter() {
+            if timestamp + shared::AI_REQUEST_WINDOW > now {
+                if window_reset == 0 {
+                    window_reset = timestamp + shared::AI_REQUEST_WINDOW;
+                }
+                requests_in_window += 1;
+            }
+        }
+        
+        let remaining = self.ai_request_limit(&user).saturating_sub(requests_in_window);
+        let cooldown_expiry = self.ai_learning_cooldown_expiry(&user);
+        let cooldown_expiry = if cooldown_expiry > now { cooldown_expiry } else { 0 };
+        
+        (remaining, window_reset, cooldown_expiry).into()
+    }
+}
@@ -49,54 +49,6 @@ pub trait AnalyticsModule:
         );
     }
     
-    #[endpoint(recordBattleOutcome)]
-    fn record_battle_outcome(
-        &self,
-        hero1_id: u64,
-        hero2_id: u64,
-        winner_id: u64,
-        battle_duration: u64,
-        damage_dealt: u64
-    ) {
-        let caller = self.blockchain().get_caller();
-        require!(self.is_authorized_caller(&caller), "Unauthorized caller");
-        
-        let timestamp = self.blockchain().get_block_timestamp();
-        
-        // Update battle statistics
-        self.total_battles().update(|count| count + 1);
-        
-        // Update hero battle statistics
-        self.hero_battles_fought(&hero1_id).update(|count| count + 1);
-        self.hero_battles_fought(&hero2_id).update(|count| count + 1);
-        
-        if winner_id == hero1_id {
-            self.hero_battles_won(&hero1_id).update(|count| count + 1);
-        } else if winner_id == hero2_id {
-            self.hero_battles_won(&hero2_id).update(|count| count + 1);
-        }
-        
-        // Update battle duration metrics
-        self.total_battle_time().update(|time| time + battle_duration);
-        self.update_average_battle_duration(battle_duration);
-        
-        // Update damage statistics
-        self.total_damage_dealt().update(|damage| damage + damage_dealt);
-        
-        // Daily battle metrics
-        let day_key = timestamp / 86400;
-        self.daily_battles(&day_key).update(|count| count + 1);
-        
-        // Emit battle analytics event
-        self.battle_outcome_recorded_event(
-            &hero1_id,
-            &hero2_id,
-            &winner_id,
-            &battle_duration,
-            &damage_dealt
-        );
-    }
-    
     #[endpoint(recordEconomicTransaction)]
     fn record_economic_transaction(
         &self,
@@ -165,19 +117,6 @@ pub trait AnalyticsModule:
         self.total_damage_in_game().update(|damage| damage + damage_dealt + damage_taken);
     }
     
-    // Helper functions
-    fn update_average_battle_duration(&self, new_duration: u64) {
-        let total_battles = self.total_battles().get();
-        let current_avg = self.average_battle_duration().get();
-        
-        if total_battles > 0 {
-            let new_avg = ((current_avg * (total_battles - 1)) + new_duration) / total_battles;
-            self.average_battle_duration().set(new_avg);
-        } else {
-            self.average_battle_duration().set(new_duration);
-        }
-    }
-    
     // View functions for analytics
     #[view(getPlayerStats)]
     fn get_player_stats(&self, player: ManagedAddress) -> MultiValue4<u64, u64, u64, BigUint> {
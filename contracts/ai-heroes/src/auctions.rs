@@ -0,0 +1,254 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait AuctionModule:
+    shared::StorageModule
+    + shared::EventsModule
+    + crate::items::ItemsModule
+    + crate::hero_management::HeroManagementModule
+    + crate::marketplace::MarketplaceModule
+{
+    // Auctions
+    // The asset is locked for the whole auction; for Dutch auctions the reserve is the floor price
+    #[endpoint(createAuction)]
+    fn create_auction(
+        &self,
+        asset_type: AssetType,
+        asset_id: u64,
+        auction_type: AuctionType,
+        start_price: BigUint,
+        reserve_price: BigUint,
+        min_bid_increment: BigUint,
+        duration: u64,
+    ) -> u64 {
+        self.require_not_paused();
+        require!(start_price > 0, shared::ERROR_INVALID_PRICE);
+        require!(
+            (shared::MIN_AUCTION_DURATION..=shared::MAX_AUCTION_DURATION).contains(&duration),
+            shared::ERROR_INVALID_AUCTION_DURATION
+        );
+        match auction_type {
+            AuctionType::English => require!(min_bid_increment > 0, shared::ERROR_INVALID_BID_INCREMENT),
+            AuctionType::Dutch => require!(reserve_price < start_price, shared::ERROR_INVALID_RESERVE_PRICE),
+        }
+        
+        let caller = self.blockchain().get_caller();
+        self.require_asset_tradable(&asset_type, asset_id, &caller);
+        
+        let auction_id = self.auction_count().get() + 1;
+        self.auction_count().set(auction_id);
+        
+        let now = self.blockchain().get_block_timestamp();
+        let auction = Auction {
+            id: auction_id,
+            seller: caller.clone(),
+            asset_type: asset_type.clone(),
+            asset_id,
+            auction_type,
+            start_price,
+            reserve_price,
+            min_bid_increment,
+            start_time: now,
+            end_time: now + duration,
+            highest_bid: BigUint::zero(),
+            highest_bidder: None,
+        };
+        
+        self.auctions(&auction_id).set(&auction);
+        self.active_auctions(&asset_type).insert(auction_id);
+        self.asset_auction(&asset_type, &asset_id).set(auction_id);
+        
+        self.auction_created_event(
+            auction_id,
+            &caller,
+            &asset_type,
+            asset_id,
+            &auction.auction_type,
+            auction.end_time,
+        );
+        
+        auction_id
+    }
+
+    // English auctions take the bid and credit the previous bidder a claimable refund,
+    // Dutch auctions sell at the current price and refund any overpayment
+    #[payable("EGLD")]
+    #[endpoint(placeBid)]
+    fn place_bid(&self, auction_id: u64) {
+        self.require_not_paused();
+        
+        let mut auction = self.require_auction(auction_id);
+        let now = self.blockchain().get_block_timestamp();
+        require!(now < auction.end_time, shared::ERROR_AUCTION_ENDED);
+        
+        let bid = self.call_value().egld_value().clone_value();
+        let bidder = self.blockchain().get_caller();
+        require!(bidder != auction.seller, shared::ERROR_CANNOT_BUY_OWN_ASSET);
+        require!(bid >= self.minimum_bid(&auction), shared::ERROR_BID_TOO_LOW);
+        
+        match auction.auction_type {
+            AuctionType::English => {
+                if let Some(previous_bidder) = &auction.highest_bidder {
+                    self.credit_auction_refund(auction_id, previous_bidder, &auction.highest_bid);
+                }
+                
+                // Anti-sniping: a late bid always leaves a full window for counter bids
+                if auction.end_time - now < shared::AUCTION_EXTENSION_WINDOW {
+                    auction.end_time = now + shared::AUCTION_EXTENSION_WINDOW;
+                }
+                
+                auction.highest_bid = bid.clone();
+                auction.highest_bidder = Some(bidder.clone());
+                self.auctions(&auction_id).set(&auction);
+                
+                self.auction_bid_placed_event(auction_id, &bidder, &bid, auction.end_time);
+            },
+            AuctionType::Dutch => {
+                let price = self.current_auction_price(&auction);
+                let overpayment = &bid - &price;
+                
+                self.remove_auction(&auction);
+                self.settle_sale(&auction.seller, &bidder, &auction.asset_type, auction.asset_id, &price);
+                if overpayment > 0 {
+                    self.send().direct_egld(&bidder, &overpayment);
+                }
+                
+                self.auction_bid_placed_event(auction_id, &bidder, &price, now);
+                self.auction_settled_event(auction_id, true, &price);
+            },
+        }
+    }
+
+    // Anyone can settle once the auction is over. Without a bid meeting the reserve,
+    // or when the winner can no longer receive the asset, the bid is credited back instead.
+    #[endpoint(settleAuction)]
+    fn settle_auction(&self, auction_id: u64) {
+        let auction = self.require_auction(auction_id);
+        require!(
+            self.blockchain().get_block_timestamp() >= auction.end_time,
+            shared::ERROR_AUCTION_NOT_ENDED
+        );
+        
+        self.remove_auction(&auction);
+        
+        let sold = match &auction.highest_bidder {
+            Some(winner) => {
+                let sold = auction.highest_bid >= auction.reserve_price
+                    && self.can_receive_asset(&auction.asset_type, winner);
+                if sold {
+                    self.settle_sale(
+                        &auction.seller,
+                        winner,
+                        &auction.asset_type,
+                        auction.asset_id,
+                        &auction.highest_bid,
+                    );
+                } else {
+                    self.credit_auction_refund(auction_id, winner, &auction.highest_bid);
+                }
+                sold
+            },
+            None => false,
+        };
+        
+        self.auction_settled_event(auction_id, sold, &auction.highest_bid);
+    }
+
+    #[endpoint(cancelAuction)]
+    fn cancel_auction(&self, auction_id: u64) {
+        let auction = self.require_auction(auction_id);
+        require!(auction.seller == self.blockchain().get_caller(), shared::ERROR_NOT_AUCTION_SELLER);
+        require!(auction.highest_bidder.is_none(), shared::ERROR_AUCTION_HAS_BIDS);
+        
+        self.remove_auction(&auction);
+        self.auction_cancelled_event(auction_id, &auction.seller);
+    }
+
+    #[endpoint(claimAuctionRefund)]
+    fn claim_auction_refund(&self) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        let amount = self.auction_refunds(&caller).take();
+        require!(amount > 0, shared::ERROR_NO_REFUND_TO_CLAIM);
+        
+        self.send().direct_egld(&caller, &amount);
+        self.auction_refund_claimed_event(&caller, &amount);
+        
+        amount
+    }
+
+    // Views
+    #[view(getAuctionPrice)]
+    fn get_auction_price(&self, auction_id: u64) -> BigUint {
+        let auction = self.require_auction(auction_id);
+        self.minimum_bid(&auction)
+    }
+
+    // Pages over auctions that are still accepting bids
+    #[view(getActiveAuctions)]
+    fn get_active_auctions(&self, asset_type: AssetType, from: usize, size: usize) -> MultiValueEncoded<Auction> {
+        let now = self.blockchain().get_block_timestamp();
+        let mut result = MultiValueEncoded::new();
+        let auctions = self
+            .active_auctions(&asset_type)
+            .iter()
+            .map(|auction_id| self.auctions(&auction_id).get())
+            .filter(|auction| now < auction.end_time)
+            .skip(from)
+            .take(size.min(shared::MAX_LISTINGS_PAGE_SIZE));
+        
+        for auction in auctions {
+            result.push(auction);
+        }
+        
+        result
+    }
+
+    // Auction helpers
+    fn minimum_bid(&self, auction: &Auction) -> BigUint {
+        match auction.auction_type {
+            AuctionType::English => match auction.highest_bidder {
+                Some(_) => &auction.highest_bid + &auction.min_bid_increment,
+                None => auction.start_price.clone(),
+            },
+            AuctionType::Dutch => self.current_auction_price(auction),
+        }
+    }
+
+    // Linear decay from the start price down to the reserve over the auction duration
+    fn current_auction_price(&self, auction: &Auction) -> BigUint {
+        let now = self.blockchain().get_block_timestamp();
+        let duration = auction.end_time - auction.start_time;
+        let elapsed = core::cmp::min(now - auction.start_time, duration);
+        
+        let decay = (&auction.start_price - &auction.reserve_price) * elapsed / duration;
+        &auction.start_price - &decay
+    }
+
+    fn can_receive_asset(&self, asset_type: &AssetType, buyer: &ManagedAddress) -> bool {
+        match asset_type {
+            AssetType::Hero => self.user_heroes(buyer).len() < shared::MAX_HEROES_PER_ACCOUNT as usize,
+            AssetType::Item => self.user_items(buyer).len() < shared::MAX_ITEMS_PER_ACCOUNT as usize,
+        }
+    }
+
+    fn credit_auction_refund(&self, auction_id: u64, bidder: &ManagedAddress, amount: &BigUint) {
+        self.auction_refunds(bidder).update(|balance| *balance += amount);
+        self.auction_refund_credited_event(auction_id, bidder, amount);
+    }
+
+    fn require_auction(&self, auction_id: u64) -> Auction {
+        require!(!self.auctions(&auction_id).is_empty(), shared::ERROR_AUCTION_NOT_FOUND);
+        self.auctions(&auction_id).get()
+    }
+
+    fn remove_auction(&self, auction: &Auction) {
+        self.auctions(&auction.id).clear();
+        self.active_auctions(&auction.asset_type).swap_remove(&auction.id);
+        self.asset_auction(&auction.asset_type, &auction.asset_id).clear();
+    }
+
+    fn require_not_paused(&self);
+}
@@ -0,0 +1,289 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait BattleModule:
+    shared::StorageModule
+    + shared::EventsModule
+    + crate::hero_management::HeroManagementModule
+    + shared::RandomnessModule
+{
+    // Battle Lifecycle
+    // PvP and tournament battles are only opened by the contract, after fees are escrowed and heroes are paired
+    #[endpoint(createBattle)]
+    fn create_battle(&self, battle_type: BattleType, hero_id: u64, ai_difficulty: u32) -> u64 {
+        self.require_not_paused();
+        require!(
+            matches!(battle_type, BattleType::PvE | BattleType::AITraining),
+            shared::ERROR_INVALID_BATTLE_TYPE
+        );
+        
+        let caller = self.blockchain().get_caller();
+        self.require_hero_user(hero_id, &caller);
+        self.require_hero_available(hero_id);
+        
+        let mut participants = ManagedVec::new();
+        participants.push(caller.clone());
+        let mut hero_ids = ManagedVec::new();
+        hero_ids.push(hero_id);
+        
+        // While pending, start_time holds the creation time used for the timeout
+        let battle_id = self.create_battle_record(battle_type, participants, hero_ids, ai_difficulty);
+        self.user_battles(&caller).insert(battle_id);
+        self.hero_active_battle(&hero_id).set(battle_id);
+        
+        battle_id
+    }
+
+    #[endpoint(joinBattle)]
+    fn join_battle(&self, battle_id: u64, hero_id: u64) {
+        self.require_not_paused();
+        
+        let mut battle = self.require_pending_battle(battle_id);
+        require!(!self.is_battle_timed_out(&battle), shared::ERROR_BATTLE_TIMEOUT);
+        require!(battle.hero_ids.len() < shared::MAX_BATTLE_PARTICIPANTS, shared::ERROR_BATTLE_FULL);
+        
+        let caller = self.blockchain().get_caller();
+        require!(!battle.participants.contains(&caller), shared::ERROR_ALREADY_BATTLE_PARTICIPANT);
+        self.require_hero_user(hero_id, &caller);
+        require!(self.hero_active_battle(&hero_id).is_empty(), shared::ERROR_HERO_ALREADY_IN_BATTLE);
+        self.require_hero_available(hero_id);
+        
+        battle.participants.push(caller.clone());
+        battle.hero_ids.push(hero_id);
+        self.battles(&battle_id).set(&battle);
+        
+        self.user_battles(&caller).insert(battle_id);
+        self.hero_active_battle(&hero_id).set(battle_id);
+    }
+
+    #[endpoint(startBattle)]
+    fn start_battle(&self, battle_id: u64) {
+        self.require_not_paused();
+        
+        let mut battle = self.require_pending_battle(battle_id);
+        self.require_battle_creator(&battle);
+        require!(!self.is_battle_timed_out(&battle), shared::ERROR_BATTLE_TIMEOUT);
+        require!(battle.hero_ids.len() >= 2, shared::ERROR_INSUFFICIENT_PARTICIPANTS);
+        
+        battle.status = BattleStatus::InProgress;
+        battle.start_time = self.blockchain().get_block_timestamp();
+        self.battles(&battle_id).set(&battle);
+        
+        self.battle_started_event(battle_id, &battle.participants, &battle.battle_type, battle.ai_difficulty);
+    }
+
+    #[endpoint(cancelBattle)]
+    fn cancel_battle(&self, battle_id: u64) {
+        let battle = self.require_pending_battle(battle_id);
+        self.require_battle_creator(&battle);
+        
+        self.close_battle(battle);
+    }
+
+    // Anyone can clear a pending battle that was never started in time
+    #[endpoint(expireBattle)]
+    fn expire_battle(&self, battle_id: u64) {
+        let battle = self.require_pending_battle(battle_id);
+        require!(self.is_battle_timed_out(&battle), shared::ERROR_BATTLE_NOT_TIMED_OUT);
+        
+        self.close_battle(battle);
+    }
+
+    // Battle Resolution
+    // Permissionless, but the seed is drawn here, so contracts cannot resolve and revert a loss
+    #[endpoint(resolveBattle)]
+    fn resolve_battle(&self, battle_id: u64) -> ManagedAddress {
+        self.require_not_paused();
+        self.require_caller_not_contract();
+        require!(!self.battles(&battle_id).is_empty(), shared::ERROR_BATTLE_NOT_FOUND);
+        
+        let mut battle = self.battles(&battle_id).get();
+        match battle.status {
+            BattleStatus::Pending => sc_panic!(shared::ERROR_BATTLE_NOT_STARTED),
+            BattleStatus::Completed | BattleStatus::Cancelled => sc_panic!(shared::ERROR_BATTLE_FINISHED),
+            BattleStatus::InProgress => {},
+        }
+        
+        let participant_count = battle.hero_ids.len();
+        require!(participant_count >= 2, shared::ERROR_INSUFFICIENT_PARTICIPANTS);
+        
+        // Build combatants from each hero's stats, equipment and battle style.
+        // The seed comes from the protocol RNG and is stored so anyone can replay the fight.
+        let mut combatants = [Combatant::default(); shared::MAX_BATTLE_PARTICIPANTS];
+        let mut levels = [0u64; shared::MAX_BATTLE_PARTICIPANTS];
+        for (index, hero_id) in battle.hero_ids.iter().enumerate() {
+            let hero = self.heroes(&hero_id).get();
+            combatants[index] = self.build_combatant(&hero);
+            levels[index] = hero.level as u64;
+            
+            // Equipped items wear down and gain usage once per battle
+            for item_id in self.equipped_item_ids(hero_id, &hero.equipment).iter() {
+                self.record_item_battle_use(item_id);
+            }
+        }
+        
+        let seed = self.random_u64(shared::RANDOM_DOMAIN_BATTLE);
+        self.battle_seeds(&battle_id).set(seed);
+        let outcome = resolve_combat(&mut combatants[..participant_count], seed);
+        let total_levels: u64 = levels[..participant_count].iter().sum();
+        
+        // Award experience and record per-hero results
+        let mut winner_experience = 0u64;
+        for (index, hero_id) in battle.hero_ids.iter().enumerate() {
+            let won = index == outcome.winner;
+            let base_experience = battle_experience(won, outcome.rounds, total_levels - levels[index]);
+            let experience = base_experience * (100 + self.experience_boost_percentage(hero_id)) / 100;
+            self.consume_battle_buffs(hero_id);
+            if won {
+                winner_experience = experience;
+            }
+            
+            self.heroes(&hero_id).update(|hero| hero.experience += experience);
+            self.hero_active_battle(&hero_id).clear();
+            self.battle_results(&battle_id).push(&BattleParticipantResult {
+                hero_id,
+                damage_dealt: outcome.damage_dealt[index],
+                damage_taken: outcome.damage_taken[index],
+                experience,
+            });
+        }
+        
+        let winner = battle.participants.get(outcome.winner).clone_value();
+        self.battle_winner_hero(&battle_id).set(battle.hero_ids.get(outcome.winner));
+        let end_time = self.blockchain().get_block_timestamp();
+        
+        // Escrowed match fees go to the winner
+        let escrow = self.battle_escrow(&battle_id).take();
+        if escrow > 0 {
+            self.pay_hero_reward(battle.hero_ids.get(outcome.winner), &winner, &escrow);
+            battle.rewards.tokens = escrow;
+        }
+        
+        battle.status = BattleStatus::Completed;
+        battle.end_time = Some(end_time);
+        battle.winner = Some(winner.clone());
+        battle.rewards.experience = winner_experience;
+        self.battles(&battle_id).set(&battle);
+        self.active_battles().swap_remove(&battle_id);
+        
+        self.battle_completed_event(battle_id, &winner, end_time - battle.start_time, winner_experience);
+        
+        winner
+    }
+
+    // Lifecycle helpers
+    fn create_battle_record(
+        &self,
+        battle_type: BattleType,
+        participants: ManagedVec<ManagedAddress>,
+        hero_ids: ManagedVec<u64>,
+        ai_difficulty: u32,
+    ) -> u64 {
+        let battle_id = self.battle_count().get() + 1;
+        self.battle_count().set(battle_id);
+        
+        let battle = Battle {
+            id: battle_id,
+            participants,
+            hero_ids,
+            battle_type,
+            status: BattleStatus::Pending,
+            start_time: self.blockchain().get_block_timestamp(),
+            end_time: None,
+            winner: None,
+            rewards: BattleRewards {
+                experience: 0,
+                tokens: BigUint::zero(),
+                items: ManagedVec::new(),
+                ai_evolution_points: 0,
+            },
+            ai_difficulty,
+        };
+        
+        self.battles(&battle_id).set(&battle);
+        self.active_battles().insert(battle_id);
+        
+        battle_id
+    }
+
+    // Starts a battle right away for heroes paired by the contract (PvP, tournaments)
+    fn start_matched_battle(&self, battle_type: BattleType, hero_ids: ManagedVec<u64>) -> u64 {
+        let mut participants = ManagedVec::new();
+        for hero_id in hero_ids.iter() {
+            participants.push(self.hero_user(hero_id));
+        }
+        
+        let battle_id = self.create_battle_record(battle_type, participants, hero_ids, 0);
+        let mut battle = self.battles(&battle_id).get();
+        battle.status = BattleStatus::InProgress;
+        self.battles(&battle_id).set(&battle);
+        
+        for (index, hero_id) in battle.hero_ids.iter().enumerate() {
+            self.user_battles(&battle.participants.get(index)).insert(battle_id);
+            self.hero_active_battle(&hero_id).set(battle_id);
+        }
+        
+        self.battle_started_event(battle_id, &battle.participants, &battle.battle_type, battle.ai_difficulty);
+        
+        battle_id
+    }
+
+    fn require_pending_battle(&self, battle_id: u64) -> Battle {
+        require!(!self.battles(&battle_id).is_empty(), shared::ERROR_BATTLE_NOT_FOUND);
+        
+        let battle = self.battles(&battle_id).get();
+        match battle.status {
+            BattleStatus::Pending => {},
+            BattleStatus::InProgress => sc_panic!(shared::ERROR_BATTLE_ALREADY_STARTED),
+            BattleStatus::Completed | BattleStatus::Cancelled => sc_panic!(shared::ERROR_BATTLE_FINISHED),
+        }
+        
+        battle
+    }
+
+    fn require_battle_creator(&self, battle: &Battle) {
+        let caller = self.blockchain().get_caller();
+        require!(battle.participants.get(0).clone_value() == caller, shared::ERROR_NOT_BATTLE_CREATOR);
+    }
+
+    fn is_battle_timed_out(&self, battle: &Battle) -> bool {
+        self.blockchain().get_block_timestamp() >= battle.start_time + shared::BATTLE_TIMEOUT
+    }
+
+    fn close_battle(&self, mut battle: Battle) {
+        for hero_id in battle.hero_ids.iter() {
+            self.hero_active_battle(&hero_id).clear();
+        }
+        
+        battle.status = BattleStatus::Cancelled;
+        battle.end_time = Some(self.blockchain().get_block_timestamp());
+        self.battles(&battle.id).set(&battle);
+        self.active_battles().swap_remove(&battle.id);
+    }
+
+    // Combat helpers
+    fn build_combatant(&self, hero: &Hero) -> Combatant {
+        let stats = self.effective_stats(hero);
+        let mut combatant = Combatant::new(&stats, hero.level, &hero.ai_traits.battle_style);
+        
+        for item_id in self.equipped_item_ids(hero.id, &hero.equipment).iter() {
+            for effect in self.items(&item_id).get().special_effects.iter() {
+                combatant.apply_effect(&effect);
+            }
+        }
+        
+        // Stat buffs are already part of the effective stats
+        for buff in self.active_hero_buffs(hero.id).iter() {
+            if !is_stat_buff(&buff.effect.effect_type) {
+                combatant.apply_effect(&buff.effect);
+            }
+        }
+        
+        combatant
+    }
+
+    fn require_not_paused(&self);
+}
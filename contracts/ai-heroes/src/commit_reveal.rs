@@ -0,0 +1,147 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait CommitRevealModule:
+    shared::StorageModule
+    + shared::EventsModule
+    + shared::RandomnessModule
+    + crate::hero_management::HeroManagementModule
+    + crate::evolution::EvolutionModule
+{
+    // Commit-reveal Rolls
+    // The commitment is keccak256(secret). The outcome is fixed at commit time from the secret
+    // and the commit block's random seed, which the caller cannot know when signing. Revealing
+    // again or later cannot change it, and an unrevealed roll forfeits the payment.
+    #[payable("EGLD")]
+    #[endpoint(commitRoll)]
+    fn commit_roll(&self, hero_id: u64, purpose: RollPurpose, commitment: ManagedByteArray<32>) {
+        self.require_not_paused();
+        self.require_caller_not_contract();
+        
+        let payment = self.call_value().egld_value().clone_value();
+        let caller = self.blockchain().get_caller();
+        self.require_hero_owned_by(hero_id, &caller);
+        self.require_hero_available(hero_id);
+        let hero = self.heroes(&hero_id).get();
+        
+        match &purpose {
+            RollPurpose::Evolution(evolution_type) => {
+                require!(self.requires_commit_reveal(evolution_type), ERROR_INVALID_ROLL_PURPOSE);
+                self.check_evolution_requirements(&hero, evolution_type, &payment);
+            },
+            RollPurpose::AbilityRoll => {
+                self.check_ability_unlock_requirements(&hero, &payment);
+                require!(!self.rollable_abilities(&hero).is_empty(), ERROR_NO_ABILITY_TO_ROLL);
+            },
+        }
+        
+        let deadline = self.blockchain().get_block_timestamp() + ROLL_REVEAL_WINDOW;
+        self.roll_commits(&hero_id).set(&RollCommit {
+            owner: caller.clone(),
+            purpose: purpose.clone(),
+            commitment,
+            payment,
+            block_nonce: self.blockchain().get_block_nonce(),
+            block_seed: self.blockchain().get_block_random_seed(),
+            deadline,
+        });
+        
+        self.roll_committed_event(hero_id, &caller, &purpose, deadline);
+    }
+
+    #[endpoint(revealRoll)]
+    fn reveal_roll(&self, hero_id: u64, secret: ManagedBuffer) -> u64 {
+        self.require_not_paused();
+        self.require_caller_not_contract();
+        
+        let roll_commit = self.require_roll_commit(hero_id);
+        require!(roll_commit.owner == self.blockchain().get_caller(), ERROR_NOT_ROLL_COMMITTER);
+        require!(
+            self.blockchain().get_block_nonce() > roll_commit.block_nonce,
+            ERROR_ROLL_REVEAL_TOO_EARLY
+        );
+        require!(
+            self.blockchain().get_block_timestamp() < roll_commit.deadline,
+            ERROR_ROLL_COMMIT_EXPIRED
+        );
+        require!(
+            self.crypto().keccak256(&secret) == roll_commit.commitment,
+            ERROR_INVALID_ROLL_REVEAL
+        );
+        
+        self.roll_commits(&hero_id).clear();
+        let mut seed_data = secret;
+        seed_data.append(roll_commit.block_seed.as_managed_buffer());
+        let seed = self.hash_to_u64(RANDOM_DOMAIN_ROLL_REVEAL, &seed_data);
+        let mut hero = self.heroes(&hero_id).get();
+        
+        match &roll_commit.purpose {
+            RollPurpose::Evolution(evolution_type) => {
+                self.apply_evolution(&mut hero, evolution_type, seed);
+                self.heroes(&hero_id).set(&hero);
+                self.sync_hero_nft(hero_id);
+                
+                let evolution_name = self.get_evolution_type_name(evolution_type);
+                self.add_revenue(&evolution_name, &roll_commit.payment);
+                
+                self.hero_evolution_triggered_event(
+                    &hero_id,
+                    evolution_type,
+                    &hero.rarity,
+                    &hero.level
+                );
+            },
+            RollPurpose::AbilityRoll => {
+                let abilities = self.rollable_abilities(&hero);
+                let ability_id = abilities.get((seed % abilities.len() as u64) as usize);
+                hero.special_abilities.push(ability_id);
+                self.heroes(&hero_id).set(&hero);
+                
+                self.add_revenue(&ManagedBuffer::from(b"ability_unlock"), &roll_commit.payment);
+                self.special_ability_unlocked_event(&hero_id, &ability_id);
+            },
+        }
+        
+        self.roll_revealed_event(hero_id, &roll_commit.purpose, seed);
+        
+        seed
+    }
+
+    // Anyone can release a hero whose roll was never revealed; the payment is kept
+    #[endpoint(forfeitRollCommit)]
+    fn forfeit_roll_commit(&self, hero_id: u64) {
+        let roll_commit = self.require_roll_commit(hero_id);
+        require!(
+            self.blockchain().get_block_timestamp() >= roll_commit.deadline,
+            ERROR_ROLL_COMMIT_NOT_EXPIRED
+        );
+        
+        self.roll_commits(&hero_id).clear();
+        self.add_revenue(&ManagedBuffer::from(b"forfeited_roll"), &roll_commit.payment);
+        
+        self.roll_forfeited_event(hero_id, &roll_commit.owner, &roll_commit.payment);
+    }
+
+    // Roll helpers
+    fn require_roll_commit(&self, hero_id: u64) -> RollCommit {
+        require!(!self.roll_commits(&hero_id).is_empty(), ERROR_ROLL_COMMIT_NOT_FOUND);
+        self.roll_commits(&hero_id).get()
+    }
+
+    fn rollable_abilities(&self, hero: &Hero) -> ManagedVec<u32> {
+        let mut abilities = ManagedVec::new();
+        for ability_id in ELEMENTAL_ABILITIES {
+            if !self.hero_has_ability(hero, ability_id) {
+                abilities.push(ability_id);
+            }
+        }
+        
+        abilities
+    }
+
+    fn require_not_paused(&self);
+    fn add_revenue(&self, fee_type: &ManagedBuffer, amount: &BigUint);
+}
@@ -0,0 +1,212 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait CraftingModule:
+    shared::StorageModule
+    + shared::EventsModule
+    + shared::RandomnessModule
+    + crate::items::ItemsModule
+{
+    // Recipe Registry
+    // Recipes carry no effects, so consumables cannot be a recipe output
+    #[only_owner]
+    #[endpoint(addCraftingRecipe)]
+    fn add_crafting_recipe(
+        &self,
+        name: ManagedBuffer,
+        output_type: ItemType,
+        output_rarity: Rarity,
+        fee: BigUint,
+        success_chance: u64,
+        inputs: MultiValueEncoded<MultiValue2<ItemType, Rarity>>,
+    ) -> u64 {
+        require!(!name.is_empty() && name.len() <= shared::MAX_NAME_LENGTH, shared::ERROR_INVALID_ITEM_NAME);
+        require!(output_type != ItemType::Consumable, shared::ERROR_INVALID_ITEM_TYPE);
+        require!(
+            (1..=shared::BASIS_POINTS_DIVISOR).contains(&success_chance),
+            shared::ERROR_INVALID_RECIPE
+        );
+        
+        let mut recipe_inputs = ManagedVec::new();
+        for input in inputs.into_iter() {
+            let (item_type, rarity) = input.into_tuple();
+            recipe_inputs.push(RecipeInput { item_type, rarity });
+        }
+        require!(
+            !recipe_inputs.is_empty() && recipe_inputs.len() <= shared::MAX_RECIPE_INPUTS,
+            shared::ERROR_INVALID_RECIPE
+        );
+        
+        let recipe_id = self.crafting_recipe_count().get() + 1;
+        self.crafting_recipe_count().set(recipe_id);
+        
+        self.crafting_recipes(&recipe_id).set(&CraftingRecipe {
+            id: recipe_id,
+            name,
+            inputs: recipe_inputs,
+            output_type,
+            output_rarity,
+            fee,
+            success_chance,
+            active: true,
+        });
+        
+        recipe_id
+    }
+
+    #[only_owner]
+    #[endpoint(setCraftingRecipeActive)]
+    fn set_crafting_recipe_active(&self, recipe_id: u64, active: bool) {
+        require!(!self.crafting_recipes(&recipe_id).is_empty(), shared::ERROR_RECIPE_NOT_FOUND);
+        self.crafting_recipes(&recipe_id).update(|recipe| recipe.active = active);
+    }
+
+    // Crafting
+    // Item ids are matched against the recipe inputs in order; inputs are burned even when the roll fails.
+    // Contracts could revert a failed roll to keep their inputs, so only user accounts may craft.
+    #[payable("EGLD")]
+    #[endpoint(craftWithRecipe)]
+    fn craft_with_recipe(&self, recipe_id: u64, item_ids: MultiValueEncoded<u64>) -> OptionalValue<u64> {
+        self.require_not_paused();
+        self.require_caller_not_contract();
+        
+        require!(!self.crafting_recipes(&recipe_id).is_empty(), shared::ERROR_RECIPE_NOT_FOUND);
+        let recipe = self.crafting_recipes(&recipe_id).get();
+        require!(recipe.active, shared::ERROR_RECIPE_INACTIVE);
+        
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == recipe.fee, shared::ERROR_INVALID_PAYMENT);
+        
+        let caller = self.blockchain().get_caller();
+        let item_ids = item_ids.to_vec();
+        let inputs = self.require_crafting_inputs(&caller, &item_ids);
+        require!(inputs.len() == recipe.inputs.len(), shared::ERROR_RECIPE_INPUT_MISMATCH);
+        for (input, required) in inputs.iter().zip(recipe.inputs.iter()) {
+            require!(
+                input.item_type == required.item_type && input.rarity == required.rarity,
+                shared::ERROR_RECIPE_INPUT_MISMATCH
+            );
+        }
+        
+        let output_item_id = self.finish_crafting(
+            &caller,
+            &item_ids,
+            recipe.success_chance,
+            recipe.name,
+            recipe.output_type,
+            recipe.output_rarity,
+        );
+        
+        if payment > 0 {
+            self.add_revenue(&ManagedBuffer::from(b"crafting"), &payment);
+        }
+        self.recipe_crafted_event(&caller, recipe_id, output_item_id.is_some(), output_item_id.unwrap_or_default());
+        
+        match output_item_id {
+            Some(item_id) => OptionalValue::Some(item_id),
+            None => OptionalValue::None,
+        }
+    }
+
+    // Three items of the same type and rarity become one item of the next rarity
+    #[payable("EGLD")]
+    #[endpoint(fuseItems)]
+    fn fuse_items(&self, item_ids: MultiValueEncoded<u64>) -> OptionalValue<u64> {
+        self.require_not_paused();
+        self.require_caller_not_contract();
+        
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == shared::ITEM_FUSION_FEE, shared::ERROR_INVALID_PAYMENT);
+        
+        let caller = self.blockchain().get_caller();
+        let item_ids = item_ids.to_vec();
+        let inputs = self.require_crafting_inputs(&caller, &item_ids);
+        require!(inputs.len() == shared::ITEM_FUSION_INPUTS, shared::ERROR_FUSION_INPUT_MISMATCH);
+        
+        let first = inputs.get(0);
+        require!(first.item_type != ItemType::Consumable, shared::ERROR_INVALID_ITEM_TYPE);
+        let name = self.items(&item_ids.get(0)).get().name;
+        for input in inputs.iter() {
+            require!(
+                input.item_type == first.item_type && input.rarity == first.rarity,
+                shared::ERROR_FUSION_INPUT_MISMATCH
+            );
+        }
+        
+        let output_rarity = match first.rarity {
+            Rarity::Common => Rarity::Uncommon,
+            Rarity::Uncommon => Rarity::Rare,
+            Rarity::Rare => Rarity::Epic,
+            Rarity::Epic => Rarity::Legendary,
+            Rarity::Legendary => Rarity::Mythic,
+            Rarity::Mythic | Rarity::AI_Generated => sc_panic!(shared::ERROR_CANNOT_FUSE_RARITY),
+        };
+        
+        let output_item_id = self.finish_crafting(
+            &caller,
+            &item_ids,
+            shared::ITEM_FUSION_SUCCESS_CHANCE,
+            name,
+            first.item_type.clone(),
+            output_rarity,
+        );
+        
+        self.add_revenue(&ManagedBuffer::from(b"item_fusion"), &payment);
+        self.items_fused_event(&caller, &first.rarity, output_item_id.is_some(), output_item_id.unwrap_or_default());
+        
+        match output_item_id {
+            Some(item_id) => OptionalValue::Some(item_id),
+            None => OptionalValue::None,
+        }
+    }
+
+    // Crafting helpers
+    fn require_crafting_inputs(&self, owner: &ManagedAddress, item_ids: &ManagedVec<u64>) -> ManagedVec<RecipeInput> {
+        let mut inputs = ManagedVec::new();
+        for (index, item_id) in item_ids.iter().enumerate() {
+            self.require_item_owned_by(item_id, owner);
+            self.require_item_not_listed(item_id);
+            require!(self.item_equipped_on(&item_id).is_empty(), shared::ERROR_ITEM_EQUIPPED);
+            for previous_id in item_ids.iter().take(index) {
+                require!(previous_id != item_id, shared::ERROR_DUPLICATE_CRAFTING_INPUT);
+            }
+            
+            let item = self.items(&item_id).get();
+            inputs.push(RecipeInput {
+                item_type: item.item_type,
+                rarity: item.rarity,
+            });
+        }
+        
+        inputs
+    }
+
+    fn finish_crafting(
+        &self,
+        owner: &ManagedAddress,
+        item_ids: &ManagedVec<u64>,
+        success_chance: u64,
+        name: ManagedBuffer,
+        output_type: ItemType,
+        output_rarity: Rarity,
+    ) -> Option<u64> {
+        for item_id in item_ids.iter() {
+            self.burn_item(item_id);
+        }
+        
+        if !self.random_chance(shared::RANDOM_DOMAIN_CRAFTING, success_chance) {
+            return None;
+        }
+        
+        let stats_bonus = self.roll_item_stats(&output_type, &output_rarity);
+        let item_id = self.create_item(owner, name, output_type, output_rarity, 1, stats_bonus, ManagedVec::new(), false);
+        
+        Some(item_id)
+    }
+
+    fn require_not_paused(&self);
+    fn add_revenue(&self, fee_type: &ManagedBuffer, amount: &BigUint);
+}
@@ -9,6 +9,7 @@ use shared::*;
 pub trait EvolutionModule:
     shared::StorageModule
     + shared::EventsModule
+    + crate::hero_management::HeroManagementModule
 {
     // Evolution System
     #[endpoint(triggerEvolution)]
@@ -18,18 +19,20 @@ pub trait EvolutionModule:
         
         let payment = self.call_value().egld_value().clone_value();
         let caller = self.blockchain().get_caller();
+        self.require_hero_owned_by(hero_id, &caller);
+        self.require_hero_available(hero_id);
         let mut hero = self.heroes(&hero_id).get();
-        
-        require!(hero.owner == caller, "Only hero owner can trigger evolution");
+        require!(!self.requires_commit_reveal(&evolution_type), ERROR_COMMIT_REVEAL_REQUIRED);
         
         // Check evolution requirements
         self.check_evolution_requirements(&hero, &evolution_type, &payment);
         
-        // Apply evolution
-        self.apply_evolution(&mut hero, &evolution_type);
+        // Apply evolution (no random outcome for these types)
+        self.apply_evolution(&mut hero, &evolution_type, 0);
         
         // Update hero
         self.heroes(&hero_id).set(&hero);
+        self.sync_hero_nft(hero_id);
         
         // Track revenue
         let evolution_name = self.get_evolution_type_name(&evolution_type);
@@ -50,16 +53,13 @@ pub trait EvolutionModule:
         self.require_not_paused();
         
         let payment = self.call_value().egld_value().clone_value();
-        let ability_cost = BigUint::from(200_000_000_000_000_000u64); // 0.2 EGLD
-        require!(payment >= ability_cost, "Insufficient payment for ability unlock");
-        
         let caller = self.blockchain().get_caller();
+        self.require_hero_owned_by(hero_id, &caller);
+        self.require_hero_available(hero_id);
+        require!(!ELEMENTAL_ABILITIES.contains(&ability_id), ERROR_ABILITY_REQUIRES_ROLL);
         let mut hero = self.heroes(&hero_id).get();
-        
-        require!(hero.owner == caller, "Only hero owner can unlock abilities");
-        require!(hero.level >= 25, "Hero must be at least level 25");
+        self.check_ability_unlock_requirements(&hero, &payment);
         require!(!self.hero_has_ability(&hero, ability_id), "Ability already unlocked");
-        require!(hero.special_abilities.len() < 5, "Maximum abilities reached");
         
         // Add ability to hero
         hero.special_abilities.push(ability_id);
@@ -82,9 +82,9 @@ pub trait EvolutionModule:
         require!(payment >= ascension_cost, "Insufficient payment for ascension");
         
         let caller = self.blockchain().get_caller();
+        self.require_hero_owned_by(hero_id, &caller);
+        self.require_hero_available(hero_id);
         let mut hero = self.heroes(&hero_id).get();
-        
-        require!(hero.owner == caller, "Only hero owner can ascend hero");
         require!(hero.level >= 100, "Hero must be at least level 100");
         require!(hero.rarity == Rarity::Legendary, "Only legendary heroes can ascend");
         require!(hero.win_count >= 50, "Hero needs at least 50 battle wins");
@@ -105,6 +105,7 @@ pub trait EvolutionModule:
         }
         
         self.heroes(&hero_id).set(&hero);
+        self.sync_hero_nft(hero_id);
         
         // Track revenue
         self.add_revenue(&ManagedBuffer::from(b"hero_ascension"), &payment);
@@ -147,8 +148,19 @@ pub trait EvolutionModule:
         }
     }
     
-    // Apply evolution effects
-    fn apply_evolution(&self, hero: &mut Hero, evolution_type: &EvolutionType) {
+    fn check_ability_unlock_requirements(&self, hero: &Hero, payment: &BigUint) {
+        require!(*payment >= self.get_ability_cost(), "Insufficient payment for ability unlock");
+        require!(hero.level >= 25, "Hero must be at least level 25");
+        require!(hero.special_abilities.len() < 5, "Maximum abilities reached");
+    }
+    
+    // Only evolutions with a random outcome go through commit-reveal
+    fn requires_commit_reveal(&self, evolution_type: &EvolutionType) -> bool {
+        matches!(evolution_type, EvolutionType::ElementalInfusion)
+    }
+    
+    // Apply evolution effects, seed comes from a revealed roll for random outcomes
+    fn apply_evolution(&self, hero: &mut Hero, evolution_type: &EvolutionType, seed: u64) {
         match evolution_type {
             EvolutionType::StatBoost => {
                 // Moderate stat increases
@@ -229,7 +241,7 @@ pub trait EvolutionModule:
                 hero.intelligence += 40;
                 
                 // Add elemental ability if not present
-                let elemental_ability_id = self.get_random_elemental_ability();
+                let elemental_ability_id = self.get_random_elemental_ability(seed);
                 if !hero.special_abilities.contains(&elemental_ability_id) {
                     hero.special_abilities.push(elemental_ability_id);
                 }
@@ -255,12 +267,9 @@ pub trait EvolutionModule:
         }
     }
     
-    fn get_random_elemental_ability(&self) -> u32 {
-        // Simple pseudo-random selection based on block timestamp
-        let timestamp = self.blockchain().get_block_timestamp();
-        let elemental_abilities = [100, 101, 102, 103, 104]; // Fire, Water, Earth, Air, Lightning
-        let index = (timestamp % 5) as usize;
-        elemental_abilities[index]
+    fn get_random_elemental_ability(&self, seed: u64) -> u32 {
+        let index = (seed % ELEMENTAL_ABILITIES.len() as u64) as usize;
+        ELEMENTAL_ABILITIES[index]
     }
     
     // View functions
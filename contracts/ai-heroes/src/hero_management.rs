@@ -9,11 +9,14 @@ use shared::*;
 pub trait HeroManagementModule:
     shared::StorageModule
     + shared::EventsModule
+    + crate::items::ItemsModule
+    + crate::hero_nft::HeroNftModule
+    + shared::RandomnessModule
 {
     // Hero Creation and Management
     #[endpoint(createHero)]
     #[payable("EGLD")]
-    fn create_hero(&self, hero_class: ManagedBuffer, name: ManagedBuffer) -> u64 {
+    fn create_hero(&self, hero_class: HeroClass, name: ManagedBuffer) -> u64 {
         self.require_not_paused();
         
         let payment = self.call_value().egld_value().clone_value();
@@ -21,53 +24,17 @@ pub trait HeroManagementModule:
         require!(payment >= creation_fee, "Insufficient payment for hero creation");
         
         let caller = self.blockchain().get_caller();
-        let current_count = self.user_hero_count(&caller).get();
-        let max_heroes = 50u32; // From game settings
-        require!(current_count < max_heroes, "Maximum heroes per user reached");
+        require!(
+            self.user_heroes(&caller).len() < MAX_HEROES_PER_ACCOUNT as usize,
+            "Maximum heroes per user reached"
+        );
         
-        let hero_id = self.hero_count().get() + 1;
-        self.hero_count().set(hero_id);
-        
-        // Create hero with initial stats
-        let hero = Hero {
-            id: hero_id,
-            owner: caller.clone(),
-            name: name.clone(),
-            class: hero_class.clone(),
-            level: 1u32,
-            experience: 0u64,
-            health: 100u32,
-            attack: 20u32,
-            defense: 15u32,
-            speed: 10u32,
-            intelligence: 10u32,
-            created_at: self.blockchain().get_block_timestamp(),
-            evolved_at: 0u64,
-            battle_count: 0u32,
-            win_count: 0u32,
-            rarity: Rarity::Common,
-            equipment_slots: ManagedVec::new(),
-            special_abilities: ManagedVec::new(),
-            metadata_uri: ManagedBuffer::new(),
-        };
-        
-        // Store hero data
-        self.heroes(&hero_id).set(&hero);
-        self.user_heroes(&caller).insert(hero_id);
-        self.user_hero_count(&caller).set(current_count + 1);
+        // Store hero data and ownership
+        let hero_id = self.create_basic_hero(&caller, name, hero_class);
         
         // Track revenue
         self.add_revenue(&ManagedBuffer::from(b"hero_creation"), &payment);
         
-        // Emit event
-        self.hero_created_event(
-            &hero_id,
-            &caller,
-            &name,
-            &hero_class,
-            &payment
-        );
-        
         hero_id
     }
     
@@ -76,25 +43,13 @@ pub trait HeroManagementModule:
         self.require_not_paused();
         
         let caller = self.blockchain().get_caller();
-        let mut hero = self.heroes(&hero_id).get();
-        
-        require!(hero.owner == caller, "Only hero owner can transfer");
+        self.require_hero_owned_by(hero_id, &caller);
         require!(to.is_valid(), "Invalid recipient address");
         require!(caller != to, "Cannot transfer to yourself");
+        self.require_hero_available(hero_id);
         
-        // Update ownership
-        hero.owner = to.clone();
-        self.heroes(&hero_id).set(&hero);
-        
-        // Update user collections
-        self.user_heroes(&caller).swap_remove(&hero_id);
-        self.user_heroes(&to).insert(hero_id);
-        
-        let caller_count = self.user_hero_count(&caller).get();
-        let to_count = self.user_hero_count(&to).get();
-        
-        self.user_hero_count(&caller).set(caller_count - 1);
-        self.user_hero_count(&to).set(to_count + 1);
+        // Update ownership, equipped items stay with their owner
+        self.change_hero_owner(hero_id, &caller, &to);
         
         // Emit event
         self.hero_transferred_event(&hero_id, &caller, &to);
@@ -110,9 +65,9 @@ pub trait HeroManagementModule:
         require!(payment >= evolution_fee, "Insufficient payment for evolution");
         
         let caller = self.blockchain().get_caller();
+        self.require_hero_owned_by(hero_id, &caller);
+        self.require_hero_available(hero_id);
         let mut hero = self.heroes(&hero_id).get();
-        
-        require!(hero.owner == caller, "Only hero owner can evolve");
         require!(hero.level >= 10, "Hero must be at least level 10 to evolve");
         require!(hero.experience >= 1000, "Insufficient experience for evolution");
         
@@ -136,6 +91,7 @@ pub trait HeroManagementModule:
         }
         
         self.heroes(&hero_id).set(&hero);
+        self.sync_hero_nft(hero_id);
         
         // Track revenue
         self.add_revenue(&ManagedBuffer::from(b"hero_evolution"), &payment);
@@ -149,10 +105,9 @@ pub trait HeroManagementModule:
         self.require_not_paused();
         
         let caller = self.blockchain().get_caller();
+        self.require_hero_owned_by(hero_id, &caller);
         let mut hero = self.heroes(&hero_id).get();
         
-        require!(hero.owner == caller, "Only hero owner can level up");
-        
         let exp_required = (hero.level as u64) * 100; // 100 exp per level
         require!(hero.experience >= exp_required, "Insufficient experience");
         
@@ -201,11 +156,73 @@ pub trait HeroManagementModule:
         }
         
         self.heroes(&hero_id).set(&hero);
+        self.sync_hero_nft(hero_id);
         
         // Emit event
         self.hero_level_up_event(&hero_id, &hero.level);
     }
     
+    // NFT Custody
+    // Withdrawing hands the hero NFT to the owner; whoever deposits it back owns the hero in-game
+    #[endpoint(withdrawHero)]
+    fn withdraw_hero(&self, hero_id: u64) -> u64 {
+        self.require_not_paused();
+        self.require_hero_collection_issued();
+        
+        let caller = self.blockchain().get_caller();
+        self.require_hero_owned_by(hero_id, &caller);
+        self.require_hero_available(hero_id);
+        
+        // Equipped items stay with their owner
+        let mut hero = self.heroes(&hero_id).get();
+        self.unequip_all_items(&mut hero.equipment);
+        self.heroes(&hero_id).set(&hero);
+        
+        let nonce = if self.hero_nft_nonce(&hero_id).is_empty() {
+            self.mint_hero_nft(&hero)
+        } else {
+            self.sync_hero_nft(hero_id);
+            self.hero_nft_nonce(&hero_id).get()
+        };
+        
+        self.hero_owners(&hero_id).clear();
+        self.user_heroes(&caller).swap_remove(&hero_id);
+        
+        self.send().direct_esdt(&caller, &self.hero_nft_token().get_token_id(), nonce, &BigUint::from(1u32));
+        self.hero_withdrawn_event(&caller, hero_id, nonce);
+        
+        nonce
+    }
+    
+    #[payable("*")]
+    #[endpoint(depositHero)]
+    fn deposit_hero(&self) -> u64 {
+        self.require_not_paused();
+        self.require_hero_collection_issued();
+        
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.hero_nft_token().get_token_id()
+                && payment.amount == 1u64
+                && !self.hero_nft_nonce_to_id(&payment.token_nonce).is_empty(),
+            ERROR_INVALID_HERO_NFT
+        );
+        
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.user_heroes(&caller).len() < MAX_HEROES_PER_ACCOUNT as usize,
+            ERROR_MAX_HEROES_REACHED
+        );
+        
+        let hero_id = self.hero_nft_nonce_to_id(&payment.token_nonce).get();
+        self.hero_owners(&hero_id).set(&caller);
+        self.user_heroes(&caller).insert(hero_id);
+        
+        self.hero_deposited_event(&caller, hero_id, payment.token_nonce);
+        
+        hero_id
+    }
+    
     // View functions
     #[view(getHero)]
     fn get_hero(&self, hero_id: u64) -> Hero {
@@ -217,9 +234,10 @@ pub trait HeroManagementModule:
         self.user_heroes(&user).iter().collect()
     }
     
+    // Counts heroes held in-game; withdrawn heroes come back under the cap when deposited
     #[view(getUserHeroCount)]
     fn get_user_hero_count(&self, user: ManagedAddress) -> u32 {
-        self.user_hero_count(&user).get()
+        self.user_heroes(&user).len() as u32
     }
     
     #[view(getHeroStats)]
@@ -256,50 +274,409 @@ pub trait HeroManagementModule:
         result
     }
     
-    // Battle system integration
-    #[endpoint(updateBattleStats)]
-    fn update_battle_stats(&self, hero_id: u64, won: bool, exp_gained: u64) {
-        // This would typically be called by the battle system contract
-        let caller = self.blockchain().get_caller();
-        require!(self.is_authorized_caller(&caller), "Unauthorized caller");
+    // Equipment system
+    #[endpoint(equipItem)]
+    fn equip_item(&self, hero_id: u64, item_id: u64, slot: ItemType) {
+        self.require_not_paused();
         
+        let caller = self.blockchain().get_caller();
+        self.require_hero_user(hero_id, &caller);
+        self.require_hero_available(hero_id);
+        self.require_item_deposited(item_id);
+        self.require_item_owned_by(item_id, &caller);
+        self.require_item_not_listed(item_id);
+        require!(self.item_equipped_on(&item_id).is_empty(), ERROR_ITEM_ALREADY_EQUIPPED);
+        
+        let item = self.items(&item_id).get();
+        require!(item.item_type == slot, ERROR_INCOMPATIBLE_ITEM);
+        require!(item.durability > 0, ERROR_ITEM_BROKEN);
+        
+        // Replace whatever is currently in the slot
         let mut hero = self.heroes(&hero_id).get();
-        hero.battle_count += 1;
-        
-        if won {
-            hero.win_count += 1;
+        let equipment_slot = self.equipment_slot(&mut hero.equipment, &slot);
+        if let Some(previous_item_id) = equipment_slot.replace(item_id) {
+            self.item_equipped_on(&previous_item_id).clear();
+            self.item_unequipped_event(hero_id, previous_item_id, &slot);
         }
         
-        hero.experience += exp_gained;
         self.heroes(&hero_id).set(&hero);
+        self.item_equipped_on(&item_id).set(hero_id);
         
         // Emit event
-        self.hero_battle_completed_event(&hero_id, &won, &exp_gained);
+        self.item_equipped_event(hero_id, item_id, &slot);
     }
     
-    // Equipment system
-    #[endpoint(equipItem)]
-    fn equip_item(&self, hero_id: u64, item_id: u64, slot: u32) {
+    // The hero's user can clear any slot, an item owner can always take back their own item
+    #[endpoint(unequipItem)]
+    fn unequip_item(&self, hero_id: u64, slot: ItemType) {
+        self.require_not_paused();
+        
         let caller = self.blockchain().get_caller();
+        require!(!self.heroes(&hero_id).is_empty(), ERROR_HERO_NOT_FOUND);
+        self.require_hero_available(hero_id);
+        
         let mut hero = self.heroes(&hero_id).get();
+        let item_id = match self.equipment_slot(&mut hero.equipment, &slot).take() {
+            Some(item_id) => item_id,
+            None => sc_panic!(ERROR_EQUIPMENT_SLOT_EMPTY),
+        };
+        require!(
+            self.hero_user(hero_id) == caller || self.item_owners(&item_id).get() == caller,
+            ERROR_NOT_HERO_USER
+        );
         
-        require!(hero.owner == caller, "Only hero owner can equip items");
-        require!(slot < 6, "Invalid equipment slot"); // 6 equipment slots
+        self.heroes(&hero_id).set(&hero);
+        self.item_equipped_on(&item_id).clear();
         
-        // Add item to hero's equipment
-        if hero.equipment_slots.len() <= slot as usize {
-            hero.equipment_slots.push(item_id);
-        } else {
-            hero.equipment_slots.set(slot as usize, &item_id);
+        self.item_unequipped_event(hero_id, item_id, &slot);
+    }
+    
+    // Consumables
+    #[endpoint(useConsumable)]
+    fn use_consumable(&self, hero_id: u64, item_id: u64) {
+        self.require_not_paused();
+        
+        let caller = self.blockchain().get_caller();
+        self.require_hero_user(hero_id, &caller);
+        self.require_hero_available(hero_id);
+        self.require_item_owned_by(item_id, &caller);
+        self.require_item_not_listed(item_id);
+        
+        let item = self.items(&item_id).get();
+        require!(item.item_type == ItemType::Consumable, ERROR_INVALID_ITEM_TYPE);
+        require!(!item.special_effects.is_empty(), ERROR_CONSUMABLE_HAS_NO_EFFECTS);
+        
+        let mut buffs = self.active_hero_buffs(hero_id);
+        require!(buffs.len() + item.special_effects.len() <= MAX_ACTIVE_BUFFS, ERROR_MAX_BUFFS_REACHED);
+        
+        let now = self.blockchain().get_block_timestamp();
+        for effect in item.special_effects.iter() {
+            let duration = if effect.duration > 0 { effect.duration as u64 } else { CONSUMABLE_DEFAULT_DURATION };
+            buffs.push(HeroBuff {
+                effect,
+                expires_at: now + duration,
+                battles_remaining: CONSUMABLE_MAX_BATTLES,
+            });
+        }
+        self.store_hero_buffs(hero_id, &buffs);
+        
+        self.burn_item(item_id);
+        self.consumable_used_event(hero_id, item_id, item.special_effects.len() as u32);
+    }
+    
+    #[view(getActiveBuffs)]
+    fn get_active_buffs(&self, hero_id: u64) -> ManagedVec<HeroBuff> {
+        self.active_hero_buffs(hero_id)
+    }
+    
+    // Base stats plus the bonus of every equipped, unbroken item and active stat buffs
+    #[view(getEffectiveStats)]
+    fn get_effective_stats(&self, hero_id: u64) -> HeroStats {
+        require!(!self.heroes(&hero_id).is_empty(), ERROR_HERO_NOT_FOUND);
+        self.effective_stats(&self.heroes(&hero_id).get())
+    }
+    
+    // Equipment helpers
+    fn effective_stats(&self, hero: &Hero) -> HeroStats {
+        let mut stats = hero.stats.clone();
+        for item_id in self.equipped_item_ids(hero.id, &hero.equipment).iter() {
+            add_stats(&mut stats, &self.items(&item_id).get().stats_bonus);
+        }
+        for buff in self.active_hero_buffs(hero.id).iter() {
+            apply_stat_buff(&mut stats, &buff.effect);
+        }
+        
+        stats
+    }
+    
+    // Only gear belonging to the hero's owner or current user counts,
+    // so a former renter's items stop applying once the rental is over
+    fn equipped_item_ids(&self, hero_id: u64, equipment: &Equipment) -> ManagedVec<u64> {
+        let owner = self.hero_owners(&hero_id).get();
+        let user = self.hero_user(hero_id);
+        let mut item_ids = ManagedVec::new();
+        let slots = [
+            equipment.weapon,
+            equipment.armor,
+            equipment.helmet,
+            equipment.boots,
+            equipment.accessory,
+        ];
+        
+        for item_id in slots.iter().flatten() {
+            if self.items(item_id).is_empty() || self.items(item_id).get().durability == 0 {
+                continue;
+            }
+            
+            let item_owner = self.item_owners(item_id).get();
+            if item_owner == owner || item_owner == user {
+                item_ids.push(*item_id);
+            }
+        }
+        
+        item_ids
+    }
+    
+    fn equipment_slot<'a>(&self, equipment: &'a mut Equipment, slot: &ItemType) -> &'a mut Option<u64> {
+        match slot {
+            ItemType::Weapon => &mut equipment.weapon,
+            ItemType::Armor => &mut equipment.armor,
+            ItemType::Helmet => &mut equipment.helmet,
+            ItemType::Boots => &mut equipment.boots,
+            ItemType::Accessory => &mut equipment.accessory,
+            ItemType::Consumable => sc_panic!(ERROR_INVALID_ITEM_TYPE),
+        }
+    }
+    
+    fn active_hero_buffs(&self, hero_id: u64) -> ManagedVec<HeroBuff> {
+        let now = self.blockchain().get_block_timestamp();
+        let mut buffs = ManagedVec::new();
+        for buff in self.hero_buffs(&hero_id).iter() {
+            if buff.expires_at > now && buff.battles_remaining > 0 {
+                buffs.push(buff);
+            }
+        }
+        
+        buffs
+    }
+    
+    fn store_hero_buffs(&self, hero_id: u64, buffs: &ManagedVec<HeroBuff>) {
+        let mut hero_buffs = self.hero_buffs(&hero_id);
+        hero_buffs.clear();
+        for buff in buffs.iter() {
+            hero_buffs.push(&buff);
+        }
+    }
+    
+    // Each battle uses up one charge of every active buff, expired ones are dropped
+    fn consume_battle_buffs(&self, hero_id: u64) {
+        let mut remaining = ManagedVec::new();
+        for mut buff in self.active_hero_buffs(hero_id).iter() {
+            buff.battles_remaining -= 1;
+            if buff.battles_remaining > 0 {
+                remaining.push(buff);
+            }
+        }
+        self.store_hero_buffs(hero_id, &remaining);
+    }
+    
+    fn experience_boost_percentage(&self, hero_id: u64) -> u64 {
+        let mut boost = 0u64;
+        for buff in self.active_hero_buffs(hero_id).iter() {
+            if buff.effect.effect_type == EffectType::AILearningBoost {
+                boost += buff.effect.value as u64;
+            }
         }
         
+        boost
+    }
+    
+    fn unequip_all_items(&self, equipment: &mut Equipment) {
+        let slots = [
+            &mut equipment.weapon,
+            &mut equipment.armor,
+            &mut equipment.helmet,
+            &mut equipment.boots,
+            &mut equipment.accessory,
+        ];
+        
+        for slot in slots {
+            if let Some(item_id) = slot.take() {
+                self.item_equipped_on(&item_id).clear();
+            }
+        }
+    }
+    
+    // Single place where ownership moves; equipped items stay with the previous owner
+    fn change_hero_owner(&self, hero_id: u64, from: &ManagedAddress, to: &ManagedAddress) {
+        require!(
+            self.user_heroes(to).len() < MAX_HEROES_PER_ACCOUNT as usize,
+            ERROR_MAX_HEROES_REACHED
+        );
+        
+        let mut hero = self.heroes(&hero_id).get();
+        self.unequip_all_items(&mut hero.equipment);
         self.heroes(&hero_id).set(&hero);
         
-        // Emit event
-        self.item_equipped_event(&hero_id, &item_id, &slot);
+        self.hero_owners(&hero_id).set(to);
+        self.user_heroes(from).swap_remove(&hero_id);
+        self.user_heroes(to).insert(hero_id);
+    }
+    
+    // Hero creation helpers
+    fn create_basic_hero(&self, owner: &ManagedAddress, name: ManagedBuffer, hero_class: HeroClass) -> u64 {
+        let hero_id = self.hero_count().get() + 1;
+        self.hero_count().set(hero_id);
+        
+        let stats = self.generate_basic_stats(&hero_class);
+        
+        let ai_traits = AITraits {
+            personality: ManagedBuffer::from(b"Balanced"),
+            battle_style: BattleStyle::Balanced,
+            adaptation_rate: 50,
+            learning_factor: 50,
+            ai_seed: self.random_u64(RANDOM_DOMAIN_AI_SEED),
+        };
+        
+        let hero = Hero {
+            id: hero_id,
+            name,
+            class: hero_class.clone(),
+            rarity: Rarity::Common,
+            level: 1,
+            experience: 0,
+            stats,
+            equipment: Equipment {
+                weapon: None,
+                armor: None,
+                helmet: None,
+                boots: None,
+                accessory: None,
+            },
+            ai_traits,
+            creation_timestamp: self.blockchain().get_block_timestamp(),
+            last_evolution: self.blockchain().get_block_timestamp(),
+        };
+        
+        self.heroes(&hero_id).set(hero);
+        self.hero_owners(&hero_id).set(owner);
+        self.user_heroes(owner).insert(hero_id);
+        
+        self.hero_created_event(owner, hero_id, &hero_class, false);
+        
+        hero_id
+    }
+    
+    fn generate_basic_stats(&self, hero_class: &HeroClass) -> HeroStats {
+        match hero_class {
+            HeroClass::Warrior => HeroStats {
+                strength: BASE_STAT_POINTS + 30,
+                intelligence: BASE_STAT_POINTS,
+                agility: BASE_STAT_POINTS + 10,
+                vitality: BASE_STAT_POINTS + 20,
+                luck: BASE_STAT_POINTS,
+                magic_power: BASE_STAT_POINTS - 10,
+            },
+            HeroClass::Mage => HeroStats {
+                strength: BASE_STAT_POINTS - 10,
+                intelligence: BASE_STAT_POINTS + 30,
+                agility: BASE_STAT_POINTS,
+                vitality: BASE_STAT_POINTS,
+                luck: BASE_STAT_POINTS + 10,
+                magic_power: BASE_STAT_POINTS + 20,
+            },
+            HeroClass::Rogue => HeroStats {
+                strength: BASE_STAT_POINTS + 10,
+                intelligence: BASE_STAT_POINTS + 10,
+                agility: BASE_STAT_POINTS + 30,
+                vitality: BASE_STAT_POINTS,
+                luck: BASE_STAT_POINTS + 20,
+                magic_power: BASE_STAT_POINTS - 10,
+            },
+            HeroClass::Paladin => HeroStats {
+                strength: BASE_STAT_POINTS + 20,
+                intelligence: BASE_STAT_POINTS + 10,
+                agility: BASE_STAT_POINTS,
+                vitality: BASE_STAT_POINTS + 20,
+                luck: BASE_STAT_POINTS,
+                magic_power: BASE_STAT_POINTS + 10,
+            },
+            HeroClass::Necromancer => HeroStats {
+                strength: BASE_STAT_POINTS,
+                intelligence: BASE_STAT_POINTS + 25,
+                agility: BASE_STAT_POINTS + 5,
+                vitality: BASE_STAT_POINTS - 5,
+                luck: BASE_STAT_POINTS + 5,
+                magic_power: BASE_STAT_POINTS + 30,
+            },
+            HeroClass::Elementalist => HeroStats {
+                strength: BASE_STAT_POINTS - 5,
+                intelligence: BASE_STAT_POINTS + 20,
+                agility: BASE_STAT_POINTS + 15,
+                vitality: BASE_STAT_POINTS + 5,
+                luck: BASE_STAT_POINTS + 15,
+                magic_power: BASE_STAT_POINTS + 25,
+            },
+        }
+    }
+    
+    fn determine_battle_style(&self, hero_class: &HeroClass) -> BattleStyle {
+        match hero_class {
+            HeroClass::Warrior => BattleStyle::Aggressive,
+            HeroClass::Mage => BattleStyle::Tactical,
+            HeroClass::Rogue => BattleStyle::Balanced,
+            HeroClass::Paladin => BattleStyle::Defensive,
+            HeroClass::Necromancer => BattleStyle::Tactical,
+            HeroClass::Elementalist => BattleStyle::Balanced,
+        }
+    }
+    
+    // Rentals
+    // The renter uses the hero until the rental expires, afterwards the owner does again
+    fn hero_user(&self, hero_id: u64) -> ManagedAddress {
+        match self.active_rental(hero_id) {
+            Some(rental) => rental.renter,
+            None => self.hero_owners(&hero_id).get(),
+        }
+    }
+    
+    fn active_rental(&self, hero_id: u64) -> Option<Rental> {
+        if self.hero_rentals(&hero_id).is_empty() {
+            return None;
+        }
+        
+        let rental = self.hero_rentals(&hero_id).get();
+        if self.blockchain().get_block_timestamp() < rental.expires_at {
+            Some(rental)
+        } else {
+            None
+        }
+    }
+    
+    // Battle rewards won by a renter are split with the owner per the rental terms
+    fn pay_hero_reward(&self, hero_id: u64, recipient: &ManagedAddress, amount: &BigUint) {
+        let mut recipient_amount = amount.clone();
+        if !self.hero_rentals(&hero_id).is_empty() {
+            let rental = self.hero_rentals(&hero_id).get();
+            if &rental.renter == recipient {
+                let owner_amount = amount * rental.owner_share / BASIS_POINTS_DIVISOR;
+                self.send().direct_non_zero_egld(&rental.owner, &owner_amount);
+                recipient_amount -= owner_amount;
+            }
+        }
+        
+        self.send().direct_non_zero_egld(recipient, &recipient_amount);
     }
     
     // Helper functions
+    // Owner-level actions are suspended while the hero is rented out
+    fn require_hero_owned_by(&self, hero_id: u64, address: &ManagedAddress) {
+        require!(!self.heroes(&hero_id).is_empty(), ERROR_HERO_NOT_FOUND);
+        require!(&self.hero_owners(&hero_id).get() == address, ERROR_HERO_NOT_OWNED);
+        self.require_hero_not_rented(hero_id);
+    }
+    
+    fn require_hero_user(&self, hero_id: u64, address: &ManagedAddress) {
+        require!(!self.heroes(&hero_id).is_empty(), ERROR_HERO_NOT_FOUND);
+        require!(&self.hero_user(hero_id) == address, ERROR_NOT_HERO_USER);
+    }
+    
+    fn require_hero_not_rented(&self, hero_id: u64) {
+        require!(self.active_rental(hero_id).is_none(), ERROR_HERO_RENTED);
+    }
+    
+    // Withdrawn, listed and battling heroes cannot be transferred, evolved or re-equipped
+    fn require_hero_available(&self, hero_id: u64) {
+        require!(!self.hero_owners(&hero_id).is_empty(), ERROR_HERO_WITHDRAWN);
+        require!(self.hero_active_battle(&hero_id).is_empty(), ERROR_HERO_IN_BATTLE);
+        require!(!self.pvp_queue().contains(&hero_id), ERROR_HERO_IN_BATTLE);
+        require!(self.hero_tournament(&hero_id).is_empty(), ERROR_HERO_IN_BATTLE);
+        require!(self.pending_ai_heroes(&hero_id).is_empty(), ERROR_HERO_GENERATION_PENDING);
+        require!(self.roll_commits(&hero_id).is_empty(), ERROR_ROLL_COMMIT_PENDING);
+        require!(self.asset_listing(&AssetType::Hero, &hero_id).is_empty(), ERROR_ASSET_LISTED);
+        require!(self.asset_auction(&AssetType::Hero, &hero_id).is_empty(), ERROR_ASSET_LISTED);
+    }
+    
     fn is_authorized_caller(&self, caller: &ManagedAddress) -> bool {
         // Check if caller is authorized (owner, battle contract, etc.)
         caller == &self.owner().get() || self.authorized_contracts(caller).get()
@@ -0,0 +1,94 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait HeroNftModule:
+    shared::StorageModule
+    + shared::EventsModule
+{
+    // Collection Setup
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueHeroCollection)]
+    fn issue_hero_collection(&self, token_display_name: ManagedBuffer, token_ticker: ManagedBuffer) {
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.hero_nft_token().issue_and_set_all_roles(
+            EsdtTokenType::NonFungible,
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            0,
+            None,
+        );
+    }
+
+    // Applies to heroes minted from now on, existing tokens keep their royalties
+    #[only_owner]
+    #[endpoint(setHeroNftRoyalties)]
+    fn set_hero_nft_royalties(&self, royalties: u64) {
+        require!(royalties <= shared::BASIS_POINTS_DIVISOR, shared::ERROR_INVALID_ROYALTIES);
+        self.hero_nft_royalties().set(royalties);
+    }
+
+    #[view(getHeroNftAttributes)]
+    fn get_hero_nft_attributes(&self, hero_id: u64) -> HeroNftAttributes {
+        require!(!self.heroes(&hero_id).is_empty(), shared::ERROR_HERO_NOT_FOUND);
+        self.hero_nft_attributes(&self.heroes(&hero_id).get())
+    }
+
+    // NFT helpers
+    fn require_hero_collection_issued(&self) {
+        require!(
+            self.hero_nft_token().get_token_state().is_set(),
+            shared::ERROR_HERO_COLLECTION_NOT_ISSUED
+        );
+    }
+
+    fn hero_nft_attributes(&self, hero: &Hero) -> HeroNftAttributes {
+        HeroNftAttributes {
+            hero_id: hero.id,
+            class: hero.class.clone(),
+            rarity: hero.rarity.clone(),
+            level: hero.level,
+            stats: hero.stats.clone(),
+        }
+    }
+
+    // A hero gets its token on first withdrawal, afterwards the same nonce moves in and out
+    fn mint_hero_nft(&self, hero: &Hero) -> u64 {
+        let royalties = if self.hero_nft_royalties().is_empty() {
+            shared::HERO_NFT_DEFAULT_ROYALTIES
+        } else {
+            self.hero_nft_royalties().get()
+        };
+        
+        let nonce = self.send().esdt_nft_create(
+            &self.hero_nft_token().get_token_id(),
+            &BigUint::from(1u32),
+            &hero.name,
+            &BigUint::from(royalties),
+            &ManagedBuffer::new(),
+            &self.hero_nft_attributes(hero),
+            &ManagedVec::new(),
+        );
+        
+        self.hero_nft_nonce(&hero.id).set(nonce);
+        self.hero_nft_nonce_to_id(&nonce).set(hero.id);
+        
+        nonce
+    }
+
+    // Attributes can only be updated while the contract holds the token,
+    // which is always the case for a hero that is owned in-game
+    fn sync_hero_nft(&self, hero_id: u64) {
+        let nonce = self.hero_nft_nonce(&hero_id).get();
+        if nonce == 0 || self.hero_owners(&hero_id).is_empty() {
+            return;
+        }
+        
+        let attributes = self.hero_nft_attributes(&self.heroes(&hero_id).get());
+        self.hero_nft_token().nft_update_attributes(nonce, &attributes);
+    }
+}
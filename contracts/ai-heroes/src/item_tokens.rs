@@ -0,0 +1,233 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait ItemTokensModule:
+    shared::StorageModule
+    + shared::EventsModule
+    + crate::items::ItemsModule
+{
+    // Collection Setup
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueGearCollection)]
+    fn issue_gear_collection(&self, token_display_name: ManagedBuffer, token_ticker: ManagedBuffer) {
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.gear_nft_token().issue_and_set_all_roles(
+            EsdtTokenType::NonFungible,
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            0,
+            None,
+        );
+    }
+
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueConsumableCollection)]
+    fn issue_consumable_collection(&self, token_display_name: ManagedBuffer, token_ticker: ManagedBuffer) {
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.consumable_sft_token().issue_and_set_all_roles(
+            EsdtTokenType::SemiFungible,
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            0,
+            None,
+        );
+    }
+
+    // Wallet Transfers
+    // Gear keeps its item record while withdrawn, consumables are burned into one unit of their SFT
+    #[endpoint(withdrawItem)]
+    fn withdraw_item(&self, item_id: u64) -> u64 {
+        self.require_not_paused();
+        
+        let caller = self.blockchain().get_caller();
+        self.require_item_owned_by(item_id, &caller);
+        self.require_item_not_listed(item_id);
+        require!(self.item_equipped_on(&item_id).is_empty(), shared::ERROR_ITEM_EQUIPPED);
+        
+        let item = self.items(&item_id).get();
+        let nonce = if item.item_type == ItemType::Consumable {
+            self.withdraw_consumable(&caller, &item)
+        } else {
+            self.withdraw_gear(&caller, &item)
+        };
+        
+        self.item_withdrawn_event(&caller, item_id, nonce);
+        
+        nonce
+    }
+
+    #[payable("*")]
+    #[endpoint(depositItems)]
+    fn deposit_items(&self) -> MultiValueEncoded<u64> {
+        self.require_not_paused();
+        
+        let caller = self.blockchain().get_caller();
+        let mut item_ids = MultiValueEncoded::new();
+        
+        for payment in self.call_value().all_esdt_transfers().iter() {
+            let is_gear = self.gear_nft_token().get_token_state().is_set()
+                && payment.token_identifier == self.gear_nft_token().get_token_id();
+            let is_consumable = self.consumable_sft_token().get_token_state().is_set()
+                && payment.token_identifier == self.consumable_sft_token().get_token_id();
+            
+            if is_gear {
+                item_ids.push(self.deposit_gear(&caller, &payment));
+            } else if is_consumable {
+                for item_id in self.deposit_consumables(&caller, &payment).iter() {
+                    item_ids.push(item_id);
+                }
+            } else {
+                sc_panic!(shared::ERROR_INVALID_ITEM_TOKEN);
+            }
+        }
+        
+        item_ids
+    }
+
+    // Token helpers
+    fn withdraw_gear(&self, owner: &ManagedAddress, item: &Item) -> u64 {
+        require!(
+            self.gear_nft_token().get_token_state().is_set(),
+            shared::ERROR_ITEM_COLLECTION_NOT_ISSUED
+        );
+        
+        // Wear and evolution only happen in-game, so attributes are refreshed on the way out
+        let attributes = self.gear_nft_attributes(item);
+        let nonce = if self.item_nft_nonce(&item.id).is_empty() {
+            let nonce = self.send().esdt_nft_create(
+                &self.gear_nft_token().get_token_id(),
+                &BigUint::from(1u32),
+                &item.name,
+                &BigUint::zero(),
+                &ManagedBuffer::new(),
+                &attributes,
+                &ManagedVec::new(),
+            );
+            self.item_nft_nonce(&item.id).set(nonce);
+            self.gear_nft_nonce_to_id(&nonce).set(item.id);
+            nonce
+        } else {
+            let nonce = self.item_nft_nonce(&item.id).get();
+            self.gear_nft_token().nft_update_attributes(nonce, &attributes);
+            nonce
+        };
+        
+        self.item_owners(&item.id).clear();
+        self.user_items(owner).swap_remove(&item.id);
+        
+        self.send().direct_esdt(owner, &self.gear_nft_token().get_token_id(), nonce, &BigUint::from(1u32));
+        
+        nonce
+    }
+
+    fn withdraw_consumable(&self, owner: &ManagedAddress, item: &Item) -> u64 {
+        require!(
+            self.consumable_sft_token().get_token_state().is_set(),
+            shared::ERROR_ITEM_COLLECTION_NOT_ISSUED
+        );
+        
+        let attributes = ConsumableSftAttributes {
+            name: item.name.clone(),
+            rarity: item.rarity.clone(),
+            level: item.level,
+            stats_bonus: item.stats_bonus.clone(),
+            special_effects: item.special_effects.clone(),
+            ai_generated: item.ai_generated,
+        };
+        let token_id = self.consumable_sft_token().get_token_id();
+        let attributes_hash = self.crypto().keccak256(&self.serializer().top_encode_to_managed_buffer(&attributes));
+        
+        // The contract keeps one unit of every nonce so later withdrawals can add quantity to it
+        let nonce = if self.consumable_sft_nonce(&attributes_hash).is_empty() {
+            let nonce = self.send().esdt_nft_create(
+                &token_id,
+                &BigUint::from(1u32),
+                &item.name,
+                &BigUint::zero(),
+                &ManagedBuffer::new(),
+                &attributes,
+                &ManagedVec::new(),
+            );
+            self.consumable_sft_nonce(&attributes_hash).set(nonce);
+            self.consumable_sft_attributes(&nonce).set(&attributes);
+            nonce
+        } else {
+            self.consumable_sft_nonce(&attributes_hash).get()
+        };
+        
+        self.burn_item(item.id);
+        self.send().esdt_local_mint(&token_id, nonce, &BigUint::from(1u32));
+        self.send().direct_esdt(owner, &token_id, nonce, &BigUint::from(1u32));
+        
+        nonce
+    }
+
+    fn deposit_gear(&self, owner: &ManagedAddress, payment: &EsdtTokenPayment) -> u64 {
+        require!(
+            !self.gear_nft_nonce_to_id(&payment.token_nonce).is_empty(),
+            shared::ERROR_INVALID_ITEM_TOKEN
+        );
+        self.require_item_capacity(owner);
+        
+        let item_id = self.gear_nft_nonce_to_id(&payment.token_nonce).get();
+        self.item_owners(&item_id).set(owner);
+        self.user_items(owner).insert(item_id);
+        
+        self.item_deposited_event(owner, item_id, payment.token_nonce);
+        
+        item_id
+    }
+
+    // Every deposited unit becomes its own inventory item again
+    fn deposit_consumables(&self, owner: &ManagedAddress, payment: &EsdtTokenPayment) -> ManagedVec<u64> {
+        require!(
+            !self.consumable_sft_attributes(&payment.token_nonce).is_empty(),
+            shared::ERROR_INVALID_ITEM_TOKEN
+        );
+        let quantity = payment.amount.to_u64().unwrap_or(u64::MAX);
+        require!(quantity <= shared::MAX_ITEMS_PER_ACCOUNT as u64, shared::ERROR_MAX_ITEMS_REACHED);
+        
+        self.send().esdt_local_burn(&payment.token_identifier, payment.token_nonce, &payment.amount);
+        
+        let attributes = self.consumable_sft_attributes(&payment.token_nonce).get();
+        let mut item_ids = ManagedVec::new();
+        for _ in 0..quantity {
+            let item_id = self.create_item(
+                owner,
+                attributes.name.clone(),
+                ItemType::Consumable,
+                attributes.rarity.clone(),
+                attributes.level,
+                attributes.stats_bonus.clone(),
+                attributes.special_effects.clone(),
+                attributes.ai_generated,
+            );
+            self.item_deposited_event(owner, item_id, payment.token_nonce);
+            item_ids.push(item_id);
+        }
+        
+        item_ids
+    }
+
+    fn gear_nft_attributes(&self, item: &Item) -> GearNftAttributes {
+        GearNftAttributes {
+            item_id: item.id,
+            item_type: item.item_type.clone(),
+            rarity: item.rarity.clone(),
+            level: item.level,
+            evolution_stage: item.evolution_stage,
+            durability: item.durability,
+            max_durability: item.max_durability,
+            stats_bonus: item.stats_bonus.clone(),
+        }
+    }
+
+    fn require_not_paused(&self);
+}
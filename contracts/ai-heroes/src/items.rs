@@ -0,0 +1,373 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait ItemsModule:
+    shared::StorageModule
+    + shared::EventsModule
+    + shared::RandomnessModule
+    + crate::subscriptions::SubscriptionModule
+{
+    // Item Minting
+    // Owner mints are regular items, oracle mints are flagged as AI generated
+    #[endpoint(mintItem)]
+    fn mint_item(
+        &self,
+        to: ManagedAddress,
+        name: ManagedBuffer,
+        item_type: ItemType,
+        rarity: Rarity,
+        level: u32,
+        stats_bonus: HeroStats,
+        special_effects: ManagedVec<SpecialEffect>,
+    ) -> u64 {
+        self.require_not_paused();
+        
+        let caller = self.blockchain().get_caller();
+        let ai_generated = self.ai_oracles().contains(&caller);
+        require!(caller == self.owner().get() || ai_generated, shared::ERROR_NOT_ITEM_MINTER);
+        require!((1..=shared::MAX_ITEM_LEVEL).contains(&level), shared::ERROR_INVALID_ITEM_LEVEL);
+        
+        self.create_item(&to, name, item_type, rarity, level, stats_bonus, special_effects, ai_generated)
+    }
+
+    // The rarity is rolled in this transaction, so only user accounts may craft.
+    // Consumables need effects, so they can only be minted.
+    #[payable("EGLD")]
+    #[endpoint(craftItem)]
+    fn craft_item(&self, name: ManagedBuffer, item_type: ItemType) -> u64 {
+        self.require_not_paused();
+        self.require_caller_not_contract();
+        require!(item_type != ItemType::Consumable, shared::ERROR_INVALID_ITEM_TYPE);
+        
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == shared::ITEM_CRAFTING_FEE, shared::ERROR_INVALID_PAYMENT);
+        
+        let caller = self.blockchain().get_caller();
+        let rarity = self.roll_crafted_rarity();
+        let stats_bonus = self.roll_item_stats(&item_type, &rarity);
+        
+        let item_id = self.create_item(&caller, name, item_type, rarity, 1, stats_bonus, ManagedVec::new(), false);
+        
+        self.add_revenue(&ManagedBuffer::from(b"item_crafting"), &payment);
+        
+        item_id
+    }
+
+    // Inventory
+    #[endpoint(transferItem)]
+    fn transfer_item(&self, item_id: u64, to: ManagedAddress) {
+        self.require_not_paused();
+        
+        let caller = self.blockchain().get_caller();
+        self.require_item_owned_by(item_id, &caller);
+        self.require_item_not_listed(item_id);
+        require!(self.item_equipped_on(&item_id).is_empty(), shared::ERROR_ITEM_EQUIPPED);
+        require!(caller != to, shared::ERROR_INVALID_ADDRESS);
+        self.require_item_capacity(&to);
+        
+        self.change_item_owner(item_id, &caller, &to);
+        
+        self.item_transferred_event(item_id, &caller, &to);
+    }
+
+    // Durability
+    #[payable("EGLD")]
+    #[endpoint(repairItem)]
+    fn repair_item(&self, item_id: u64) {
+        self.require_not_paused();
+        
+        let caller = self.blockchain().get_caller();
+        self.require_item_owned_by(item_id, &caller);
+        
+        let cost = self.get_repair_quote(item_id);
+        require!(cost > 0, shared::ERROR_ITEM_NOT_DAMAGED);
+        
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == cost, shared::ERROR_INVALID_PAYMENT);
+        
+        let mut item = self.items(&item_id).get();
+        let restored_durability = item.max_durability - item.durability;
+        item.durability = item.max_durability;
+        self.items(&item_id).set(&item);
+        
+        self.add_revenue(&ManagedBuffer::from(b"repair"), &payment);
+        self.item_repaired_event(item_id, &caller, restored_durability, &payment);
+    }
+
+    #[view(getRepairQuote)]
+    fn get_repair_quote(&self, item_id: u64) -> BigUint {
+        require!(!self.items(&item_id).is_empty(), shared::ERROR_ITEM_NOT_FOUND);
+        
+        let item = self.items(&item_id).get();
+        let missing_durability = (item.max_durability - item.durability) as u64;
+        BigUint::from(shared::ITEM_REPAIR_COST_PER_POINT) * missing_durability * self.rarity_tier(&item.rarity) as u64
+    }
+
+    // Evolution
+    // Usage totals needed for stages 1 to MAX_EVOLUTION_STAGE
+    #[only_owner]
+    #[endpoint(setItemEvolutionThresholds)]
+    fn set_item_evolution_thresholds(&self, thresholds: MultiValueEncoded<u64>) {
+        let thresholds = thresholds.to_vec();
+        require!(
+            thresholds.len() == shared::MAX_EVOLUTION_STAGE as usize,
+            shared::ERROR_INVALID_EVOLUTION_THRESHOLDS
+        );
+        
+        let mut previous = 0u64;
+        for threshold in thresholds.iter() {
+            require!(threshold > previous, shared::ERROR_INVALID_EVOLUTION_THRESHOLDS);
+            previous = threshold;
+        }
+        
+        self.item_evolution_thresholds().clear();
+        for threshold in thresholds.iter() {
+            self.item_evolution_thresholds().push(&threshold);
+        }
+    }
+
+    // Skips the usage requirement for the next stage
+    #[payable("EGLD")]
+    #[endpoint(accelerateItemEvolution)]
+    fn accelerate_item_evolution(&self, item_id: u64) {
+        self.require_not_paused();
+        
+        let caller = self.blockchain().get_caller();
+        self.require_item_owned_by(item_id, &caller);
+        self.require_item_not_listed(item_id);
+        
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == self.evolution_fee(&caller), shared::ERROR_INVALID_PAYMENT);
+        
+        let mut item = self.items(&item_id).get();
+        require!(item.item_type != ItemType::Consumable, shared::ERROR_INVALID_ITEM_TYPE);
+        require!(item.evolution_stage < shared::MAX_EVOLUTION_STAGE, shared::ERROR_ITEM_MAX_STAGE);
+        
+        self.evolve_item(&mut item, false);
+        self.items(&item_id).set(&item);
+        
+        self.add_revenue(&ManagedBuffer::from(b"item_evolution"), &payment);
+    }
+
+    // Stages are 1-based, matching the VecMapper index of their threshold
+    #[view(getItemEvolutionThreshold)]
+    fn get_item_evolution_threshold(&self, stage: u32) -> u64 {
+        require!(
+            (1..=shared::MAX_EVOLUTION_STAGE).contains(&stage),
+            shared::ERROR_INVALID_EVOLUTION_STAGE
+        );
+        
+        let thresholds = self.item_evolution_thresholds();
+        if thresholds.is_empty() {
+            stage as u64 * shared::ITEM_EVOLUTION_USES_PER_STAGE
+        } else {
+            thresholds.get(stage as usize)
+        }
+    }
+
+    // Called once per battle for each equipped item: rarer items wear slower,
+    // and crossing the next usage threshold evolves the item
+    fn record_item_battle_use(&self, item_id: u64) {
+        let mut item = self.items(&item_id).get();
+        if item.durability == 0 {
+            return;
+        }
+        
+        let wear = core::cmp::max(shared::ITEM_WEAR_PER_BATTLE / self.rarity_tier(&item.rarity), 1);
+        item.durability = item.durability.saturating_sub(wear);
+        item.usage_count += 1;
+        
+        if item.evolution_stage < shared::MAX_EVOLUTION_STAGE
+            && item.usage_count >= self.get_item_evolution_threshold(item.evolution_stage + 1)
+        {
+            self.evolve_item(&mut item, true);
+        }
+        self.items(&item_id).set(&item);
+        
+        if item.durability == 0 {
+            self.item_broken_event(item_id, &self.item_owners(&item_id).get());
+        }
+    }
+
+    fn evolve_item(&self, item: &mut Item, usage_based: bool) {
+        let old_stage = item.evolution_stage;
+        item.evolution_stage += 1;
+        
+        let bonus = &mut item.stats_bonus;
+        for stat in [
+            &mut bonus.strength,
+            &mut bonus.intelligence,
+            &mut bonus.agility,
+            &mut bonus.vitality,
+            &mut bonus.luck,
+            &mut bonus.magic_power,
+        ] {
+            if *stat > 0 {
+                *stat += core::cmp::max(*stat * shared::ITEM_EVOLUTION_BONUS_PERCENTAGE / 100, 1);
+            }
+        }
+        
+        // Every few stages the item gains an effect matching its slot
+        if item.evolution_stage % shared::ITEM_EFFECT_STAGE_INTERVAL == 0 {
+            let effect_type = match item.item_type {
+                ItemType::Weapon => Some(EffectType::DamageBoost),
+                ItemType::Armor => Some(EffectType::DefenseBoost),
+                ItemType::Helmet => Some(EffectType::ElementalResistance),
+                ItemType::Boots => Some(EffectType::SpeedBoost),
+                ItemType::Accessory => Some(EffectType::CriticalChance),
+                ItemType::Consumable => None,
+            };
+            if let Some(effect_type) = effect_type {
+                item.special_effects.push(SpecialEffect {
+                    effect_type,
+                    value: item.evolution_stage,
+                    duration: 0,
+                });
+            }
+        }
+        
+        self.item_evolved_event(item.id, old_stage, item.evolution_stage, usage_based);
+    }
+
+    // Item helpers
+    #[allow(clippy::too_many_arguments)]
+    fn create_item(
+        &self,
+        owner: &ManagedAddress,
+        name: ManagedBuffer,
+        item_type: ItemType,
+        rarity: Rarity,
+        level: u32,
+        stats_bonus: HeroStats,
+        special_effects: ManagedVec<SpecialEffect>,
+        ai_generated: bool,
+    ) -> u64 {
+        require!(!name.is_empty() && name.len() <= shared::MAX_NAME_LENGTH, shared::ERROR_INVALID_ITEM_NAME);
+        self.require_item_capacity(owner);
+        
+        let item_id = self.item_count().get() + 1;
+        self.item_count().set(item_id);
+        
+        let max_durability = self.item_max_durability(&rarity);
+        let item = Item {
+            id: item_id,
+            name,
+            item_type: item_type.clone(),
+            rarity: rarity.clone(),
+            level,
+            stats_bonus,
+            special_effects,
+            durability: max_durability,
+            max_durability,
+            evolution_stage: 0,
+            usage_count: 0,
+            ai_generated,
+        };
+        
+        self.items(&item_id).set(&item);
+        self.item_owners(&item_id).set(owner);
+        self.user_items(owner).insert(item_id);
+        
+        self.item_created_event(owner, item_id, &item_type, &rarity, ai_generated);
+        
+        item_id
+    }
+
+    fn burn_item(&self, item_id: u64) {
+        let owner = self.item_owners(&item_id).take();
+        self.user_items(&owner).swap_remove(&item_id);
+        self.items(&item_id).clear();
+    }
+
+    fn change_item_owner(&self, item_id: u64, from: &ManagedAddress, to: &ManagedAddress) {
+        self.item_owners(&item_id).set(to);
+        self.user_items(from).swap_remove(&item_id);
+        self.user_items(to).insert(item_id);
+    }
+
+    fn require_item_owned_by(&self, item_id: u64, address: &ManagedAddress) {
+        require!(!self.items(&item_id).is_empty(), shared::ERROR_ITEM_NOT_FOUND);
+        require!(&self.item_owners(&item_id).get() == address, shared::ERROR_ITEM_NOT_OWNED);
+    }
+
+    // Covers fixed-price listings and auctions
+    fn require_item_not_listed(&self, item_id: u64) {
+        require!(self.asset_listing(&AssetType::Item, &item_id).is_empty(), shared::ERROR_ASSET_LISTED);
+        require!(self.asset_auction(&AssetType::Item, &item_id).is_empty(), shared::ERROR_ASSET_LISTED);
+    }
+
+    // Withdrawn gear keeps its record but has no in-game owner
+    fn require_item_deposited(&self, item_id: u64) {
+        require!(!self.items(&item_id).is_empty(), shared::ERROR_ITEM_NOT_FOUND);
+        require!(!self.item_owners(&item_id).is_empty(), shared::ERROR_ITEM_NOT_DEPOSITED);
+    }
+
+    fn require_item_capacity(&self, owner: &ManagedAddress) {
+        require!(
+            self.user_items(owner).len() < shared::MAX_ITEMS_PER_ACCOUNT as usize,
+            shared::ERROR_MAX_ITEMS_REACHED
+        );
+    }
+
+    fn rarity_tier(&self, rarity: &Rarity) -> u32 {
+        match rarity {
+            Rarity::Common => 1,
+            Rarity::Uncommon => 2,
+            Rarity::Rare => 3,
+            Rarity::Epic => 4,
+            Rarity::AI_Generated => 4,
+            Rarity::Legendary => 5,
+            Rarity::Mythic => 6,
+        }
+    }
+
+    fn item_max_durability(&self, rarity: &Rarity) -> u32 {
+        shared::BASE_ITEM_DURABILITY + self.rarity_tier(rarity) * shared::ITEM_DURABILITY_PER_TIER
+    }
+
+    // Cumulative odds in basis points: 60% common, 25% uncommon, 10% rare, 4% epic, 1% legendary
+    fn roll_crafted_rarity(&self) -> Rarity {
+        let roll = self.random_in_range(shared::RANDOM_DOMAIN_LOOT, 0, shared::BASIS_POINTS_DIVISOR - 1);
+        match roll {
+            0..=5999 => Rarity::Common,
+            6000..=8499 => Rarity::Uncommon,
+            8500..=9499 => Rarity::Rare,
+            9500..=9899 => Rarity::Epic,
+            _ => Rarity::Legendary,
+        }
+    }
+
+    // Each item type boosts its own stats, scaled by rarity with a random spread
+    fn roll_item_stats(&self, item_type: &ItemType, rarity: &Rarity) -> HeroStats {
+        let tier = self.rarity_tier(rarity) as u64;
+        let bonus = (tier * 5 + self.random_in_range(shared::RANDOM_DOMAIN_LOOT, 0, tier * 5)) as u32;
+        
+        let mut stats = HeroStats {
+            strength: 0,
+            intelligence: 0,
+            agility: 0,
+            vitality: 0,
+            luck: 0,
+            magic_power: 0,
+        };
+        match item_type {
+            ItemType::Weapon => {
+                stats.strength = bonus;
+                stats.magic_power = bonus / 2;
+            },
+            ItemType::Armor => stats.vitality = bonus,
+            ItemType::Helmet => stats.intelligence = bonus,
+            ItemType::Boots => stats.agility = bonus,
+            ItemType::Accessory => stats.luck = bonus,
+            ItemType::Consumable => {},
+        }
+        
+        stats
+    }
+
+    fn require_not_paused(&self);
+    fn add_revenue(&self, fee_type: &ManagedBuffer, amount: &BigUint);
+}
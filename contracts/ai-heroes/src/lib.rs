@@ -6,18 +6,47 @@ multiversx_sc::derive_imports!();
 use shared::*;
 
 mod ai_generation;
+mod ai_quota;
 mod hero_management;
+mod hero_nft;
 mod evolution;
 mod analytics;
+mod battle;
+mod pvp;
+mod tournament;
+mod commit_reveal;
+mod items;
+mod item_tokens;
+mod crafting;
+mod marketplace;
+mod auctions;
+mod offers;
+mod rentals;
+mod subscriptions;
 
 #[multiversx_sc::contract]
 pub trait AIHeroesContract:
     shared::StorageModule
     + shared::EventsModule
+    + shared::RandomnessModule
     + ai_generation::AIGenerationModule
+    + ai_quota::AIQuotaModule
     + hero_management::HeroManagementModule
+    + hero_nft::HeroNftModule
     + evolution::EvolutionModule
     + analytics::AnalyticsModule
+    + battle::BattleModule
+    + pvp::PvPModule
+    + tournament::TournamentModule
+    + commit_reveal::CommitRevealModule
+    + items::ItemsModule
+    + item_tokens::ItemTokensModule
+    + crafting::CraftingModule
+    + marketplace::MarketplaceModule
+    + auctions::AuctionModule
+    + offers::OfferModule
+    + rentals::RentalModule
+    + subscriptions::SubscriptionModule
 {
     #[init]
     fn init(&self) {
@@ -61,10 +90,13 @@ pub trait AIHeroesContract:
     #[endpoint(withdrawFees)]
     fn withdraw_fees(&self) {
         let caller = self.blockchain().get_caller();
-        let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
         
-        if balance > 0 {
-            self.send().direct_egld(&caller, &balance);
+        // Only collected revenue is withdrawable, escrowed player funds stay in the contract
+        let withdrawable = self.total_fees().get() - self.withdrawn_fees().get();
+        
+        if withdrawable > 0 {
+            self.withdrawn_fees().update(|withdrawn| *withdrawn += &withdrawable);
+            self.send().direct_egld(&caller, &withdrawable);
         }
     }
 
@@ -0,0 +1,251 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait MarketplaceModule:
+    shared::StorageModule
+    + shared::EventsModule
+    + crate::items::ItemsModule
+    + crate::hero_management::HeroManagementModule
+{
+    // Fixed-price Listings
+    // A listed asset stays with the seller but is locked until it is sold or delisted
+    #[endpoint(listAsset)]
+    fn list_asset(
+        &self,
+        asset_type: AssetType,
+        asset_id: u64,
+        price: BigUint,
+        opt_expires_at: OptionalValue<u64>,
+    ) -> u64 {
+        self.require_not_paused();
+        require!(price > 0, shared::ERROR_INVALID_PRICE);
+        
+        let caller = self.blockchain().get_caller();
+        self.require_asset_tradable(&asset_type, asset_id, &caller);
+        
+        let now = self.blockchain().get_block_timestamp();
+        let expires_at = match opt_expires_at {
+            OptionalValue::Some(expires_at) => {
+                require!(expires_at > now, shared::ERROR_INVALID_LISTING_EXPIRY);
+                expires_at
+            },
+            OptionalValue::None => 0,
+        };
+        
+        let listing_id = self.listing_count().get() + 1;
+        self.listing_count().set(listing_id);
+        
+        self.listings(&listing_id).set(&Listing {
+            id: listing_id,
+            seller: caller.clone(),
+            asset_type: asset_type.clone(),
+            asset_id,
+            price: price.clone(),
+            created_at: now,
+            expires_at,
+        });
+        self.active_listings(&asset_type).insert(listing_id);
+        self.asset_listing(&asset_type, &asset_id).set(listing_id);
+        
+        self.asset_listed_event(listing_id, &caller, &asset_type, asset_id, &price, expires_at);
+        
+        listing_id
+    }
+
+    #[endpoint(updateListingPrice)]
+    fn update_listing_price(&self, listing_id: u64, new_price: BigUint) {
+        self.require_not_paused();
+        require!(new_price > 0, shared::ERROR_INVALID_PRICE);
+        
+        let mut listing = self.require_listing(listing_id);
+        require!(listing.seller == self.blockchain().get_caller(), shared::ERROR_NOT_LISTING_SELLER);
+        require!(!self.is_listing_expired(&listing), shared::ERROR_SALE_EXPIRED);
+        
+        let old_price = core::mem::replace(&mut listing.price, new_price);
+        self.listings(&listing_id).set(&listing);
+        
+        self.listing_price_updated_event(listing_id, &old_price, &listing.price);
+    }
+
+    // The seller can delist at any time, anyone can clear a listing that has expired
+    #[endpoint(delistAsset)]
+    fn delist_asset(&self, listing_id: u64) {
+        let listing = self.require_listing(listing_id);
+        require!(
+            listing.seller == self.blockchain().get_caller() || self.is_listing_expired(&listing),
+            shared::ERROR_NOT_LISTING_SELLER
+        );
+        
+        self.remove_listing(&listing);
+        self.asset_delisted_event(listing_id, &listing.seller);
+    }
+
+    #[payable("EGLD")]
+    #[endpoint(buyAsset)]
+    fn buy_asset(&self, listing_id: u64) {
+        self.require_not_paused();
+        
+        let listing = self.require_listing(listing_id);
+        require!(!self.is_listing_expired(&listing), shared::ERROR_SALE_EXPIRED);
+        
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == listing.price, shared::ERROR_INVALID_PAYMENT);
+        
+        let buyer = self.blockchain().get_caller();
+        require!(buyer != listing.seller, shared::ERROR_CANNOT_BUY_OWN_ASSET);
+        
+        self.remove_listing(&listing);
+        self.settle_sale(&listing.seller, &buyer, &listing.asset_type, listing.asset_id, &payment);
+    }
+
+    // Creator Royalties
+    // Applies to heroes generated from now on, existing heroes keep their rate
+    #[only_owner]
+    #[endpoint(setCreatorRoyaltyRate)]
+    fn set_creator_royalty_rate(&self, royalty_rate: u64) {
+        require!(royalty_rate <= shared::MAX_CREATOR_ROYALTY, shared::ERROR_INVALID_ROYALTIES);
+        self.creator_royalty_rate().set(royalty_rate);
+    }
+
+    #[endpoint(claimRoyalties)]
+    fn claim_royalties(&self) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        let amount = self.claimable_royalties(&caller).take();
+        require!(amount > 0, shared::ERROR_NO_ROYALTIES_TO_CLAIM);
+        
+        self.send().direct_egld(&caller, &amount);
+        self.royalties_claimed_event(&caller, &amount);
+        
+        amount
+    }
+
+    // Views
+    // Lifetime royalties per hero created by the account, claimed or not
+    #[view(getCreatorRoyalties)]
+    fn get_creator_royalties(&self, creator: ManagedAddress) -> MultiValueEncoded<MultiValue2<u64, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for hero_id in self.creator_heroes(&creator).iter() {
+            result.push((hero_id, self.hero_royalties_accrued(&hero_id).get()).into());
+        }
+        
+        result
+    }
+
+    // Pages over unexpired listings only
+    #[view(getActiveListings)]
+    fn get_active_listings(&self, asset_type: AssetType, from: usize, size: usize) -> MultiValueEncoded<Listing> {
+        let mut result = MultiValueEncoded::new();
+        let listings = self
+            .active_listings(&asset_type)
+            .iter()
+            .map(|listing_id| self.listings(&listing_id).get())
+            .filter(|listing| !self.is_listing_expired(listing))
+            .skip(from)
+            .take(size.min(shared::MAX_LISTINGS_PAGE_SIZE));
+        
+        for listing in listings {
+            result.push(listing);
+        }
+        
+        result
+    }
+
+    // Sale helpers
+    // Moves the asset to the buyer and pays the seller, minus the marketplace fee and creator royalty
+    fn settle_sale(
+        &self,
+        seller: &ManagedAddress,
+        buyer: &ManagedAddress,
+        asset_type: &AssetType,
+        asset_id: u64,
+        price: &BigUint,
+    ) {
+        self.require_asset_owner(asset_type, asset_id, seller);
+        
+        match asset_type {
+            AssetType::Hero => self.change_hero_owner(asset_id, seller, buyer),
+            AssetType::Item => {
+                self.require_item_capacity(buyer);
+                self.change_item_owner(asset_id, seller, buyer);
+            },
+        }
+        
+        let marketplace_fee = price * shared::MARKETPLACE_FEE_PERCENTAGE / shared::BASIS_POINTS_DIVISOR;
+        let creator_royalty = match asset_type {
+            AssetType::Hero => self.accrue_creator_royalty(asset_id, seller, price),
+            AssetType::Item => BigUint::zero(),
+        };
+        let seller_proceeds = price - &marketplace_fee - &creator_royalty;
+        
+        if marketplace_fee > 0 {
+            self.add_revenue(&ManagedBuffer::from(b"marketplace"), &marketplace_fee);
+        }
+        self.send().direct_egld(seller, &seller_proceeds);
+        
+        self.asset_sold_event(asset_type, asset_id, seller, buyer, price, &marketplace_fee);
+    }
+
+    // Creators selling their own hero owe themselves nothing
+    fn accrue_creator_royalty(&self, hero_id: u64, seller: &ManagedAddress, price: &BigUint) -> BigUint {
+        if self.hero_creators(&hero_id).is_empty() {
+            return BigUint::zero();
+        }
+        
+        let hero_creator = self.hero_creators(&hero_id).get();
+        if &hero_creator.creator == seller {
+            return BigUint::zero();
+        }
+        
+        let royalty = price * hero_creator.royalty_rate / shared::BASIS_POINTS_DIVISOR;
+        if royalty > 0 {
+            self.claimable_royalties(&hero_creator.creator).update(|balance| *balance += &royalty);
+            self.hero_royalties_accrued(&hero_id).update(|accrued| *accrued += &royalty);
+            self.royalty_accrued_event(hero_id, &hero_creator.creator, &royalty);
+        }
+        
+        royalty
+    }
+
+    fn require_asset_tradable(&self, asset_type: &AssetType, asset_id: u64, owner: &ManagedAddress) {
+        match asset_type {
+            AssetType::Hero => {
+                self.require_hero_owned_by(asset_id, owner);
+                self.require_hero_available(asset_id);
+            },
+            AssetType::Item => {
+                self.require_item_owned_by(asset_id, owner);
+                self.require_item_not_listed(asset_id);
+                require!(self.item_equipped_on(&asset_id).is_empty(), shared::ERROR_ITEM_EQUIPPED);
+            },
+        }
+    }
+
+    fn require_asset_owner(&self, asset_type: &AssetType, asset_id: u64, owner: &ManagedAddress) {
+        let current_owner = match asset_type {
+            AssetType::Hero => self.hero_owners(&asset_id).get(),
+            AssetType::Item => self.item_owners(&asset_id).get(),
+        };
+        require!(&current_owner == owner, shared::ERROR_ASSET_NOT_OWNED_BY_SELLER);
+    }
+
+    fn require_listing(&self, listing_id: u64) -> Listing {
+        require!(!self.listings(&listing_id).is_empty(), shared::ERROR_LISTING_NOT_FOUND);
+        self.listings(&listing_id).get()
+    }
+
+    fn remove_listing(&self, listing: &Listing) {
+        self.listings(&listing.id).clear();
+        self.active_listings(&listing.asset_type).swap_remove(&listing.id);
+        self.asset_listing(&listing.asset_type, &listing.asset_id).clear();
+    }
+
+    fn is_listing_expired(&self, listing: &Listing) -> bool {
+        listing.expires_at != 0 && self.blockchain().get_block_timestamp() >= listing.expires_at
+    }
+
+    fn require_not_paused(&self);
+    fn add_revenue(&self, fee_type: &ManagedBuffer, amount: &BigUint);
+}
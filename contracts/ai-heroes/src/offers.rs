@@ -0,0 +1,188 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait OfferModule:
+    shared::StorageModule
+    + shared::EventsModule
+    + crate::items::ItemsModule
+    + crate::hero_management::HeroManagementModule
+    + crate::marketplace::MarketplaceModule
+{
+    // Offers
+    // The offered EGLD stays in escrow until the offer is accepted, cancelled or expires
+    #[payable("EGLD")]
+    #[endpoint(makeHeroOffer)]
+    fn make_hero_offer(&self, hero_id: u64, expires_at: u64) -> u64 {
+        require!(!self.heroes(&hero_id).is_empty(), shared::ERROR_HERO_NOT_FOUND);
+        require!(
+            self.hero_owners(&hero_id).get() != self.blockchain().get_caller(),
+            shared::ERROR_CANNOT_BUY_OWN_ASSET
+        );
+        
+        let offer_id = self.create_offer(OfferTarget::Hero(hero_id), expires_at);
+        self.hero_offers(&hero_id).insert(offer_id);
+        
+        offer_id
+    }
+
+    #[payable("EGLD")]
+    #[endpoint(makeCollectionOffer)]
+    fn make_collection_offer(
+        &self,
+        class: Option<HeroClass>,
+        min_level: u32,
+        rarity: Option<Rarity>,
+        expires_at: u64,
+    ) -> u64 {
+        let filter = HeroOfferFilter { class, min_level, rarity };
+        let offer_id = self.create_offer(OfferTarget::Collection(filter), expires_at);
+        self.collection_offers().insert(offer_id);
+        
+        offer_id
+    }
+
+    // The buyer can cancel at any time, anyone can refund an offer that has expired
+    #[endpoint(cancelOffer)]
+    fn cancel_offer(&self, offer_id: u64) {
+        let offer = self.require_offer(offer_id);
+        require!(
+            offer.buyer == self.blockchain().get_caller() || self.is_offer_expired(&offer),
+            shared::ERROR_NOT_OFFER_BUYER
+        );
+        
+        self.remove_offer(&offer);
+        self.send().direct_egld(&offer.buyer, &offer.amount);
+        
+        self.offer_cancelled_event(offer_id, &offer.buyer, &offer.amount);
+    }
+
+    #[endpoint(acceptOffer)]
+    fn accept_offer(&self, offer_id: u64, hero_id: u64) {
+        self.require_not_paused();
+        
+        let offer = self.require_offer(offer_id);
+        require!(!self.is_offer_expired(&offer), shared::ERROR_OFFER_EXPIRED);
+        
+        let caller = self.blockchain().get_caller();
+        self.require_hero_owned_by(hero_id, &caller);
+        self.require_hero_available(hero_id);
+        require!(offer.buyer != caller, shared::ERROR_CANNOT_BUY_OWN_ASSET);
+        require!(
+            self.offer_matches_hero(&offer.target, &self.heroes(&hero_id).get()),
+            shared::ERROR_OFFER_TARGET_MISMATCH
+        );
+        
+        self.remove_offer(&offer);
+        self.settle_sale(&caller, &offer.buyer, &AssetType::Hero, hero_id, &offer.amount);
+        
+        self.offer_accepted_event(offer_id, &caller, hero_id);
+    }
+
+    // Views
+    #[view(getHeroOffers)]
+    fn get_hero_offers(&self, hero_id: u64) -> MultiValueEncoded<Offer> {
+        self.active_offers(self.hero_offers(&hero_id).iter().collect(), 0, shared::MAX_LISTINGS_PAGE_SIZE)
+    }
+
+    #[view(getCollectionOffers)]
+    fn get_collection_offers(&self, from: usize, size: usize) -> MultiValueEncoded<Offer> {
+        self.active_offers(self.collection_offers().iter().collect(), from, size)
+    }
+
+    // Includes expired offers so buyers can find escrow to reclaim
+    #[view(getUserOffers)]
+    fn get_user_offers(&self, user: ManagedAddress) -> MultiValueEncoded<Offer> {
+        let mut result = MultiValueEncoded::new();
+        for offer_id in self.user_offers(&user).iter() {
+            result.push(self.offers(&offer_id).get());
+        }
+        
+        result
+    }
+
+    // Offer helpers
+    fn create_offer(&self, target: OfferTarget, expires_at: u64) -> u64 {
+        self.require_not_paused();
+        
+        let amount = self.call_value().egld_value().clone_value();
+        require!(amount > 0, shared::ERROR_INVALID_PRICE);
+        
+        let now = self.blockchain().get_block_timestamp();
+        require!(
+            expires_at > now && expires_at <= now + shared::MAX_OFFER_DURATION,
+            shared::ERROR_INVALID_OFFER_EXPIRY
+        );
+        
+        let offer_id = self.offer_count().get() + 1;
+        self.offer_count().set(offer_id);
+        
+        let buyer = self.blockchain().get_caller();
+        self.offers(&offer_id).set(&Offer {
+            id: offer_id,
+            buyer: buyer.clone(),
+            target: target.clone(),
+            amount: amount.clone(),
+            created_at: now,
+            expires_at,
+        });
+        self.user_offers(&buyer).insert(offer_id);
+        
+        self.offer_made_event(offer_id, &buyer, &target, &amount, expires_at);
+        
+        offer_id
+    }
+
+    fn offer_matches_hero(&self, target: &OfferTarget, hero: &Hero) -> bool {
+        match target {
+            OfferTarget::Hero(hero_id) => *hero_id == hero.id,
+            OfferTarget::Collection(filter) => {
+                (filter.class.is_none() || filter.class.as_ref() == Some(&hero.class))
+                    && hero.level >= filter.min_level
+                    && (filter.rarity.is_none() || filter.rarity.as_ref() == Some(&hero.rarity))
+            },
+        }
+    }
+
+    fn active_offers(&self, offer_ids: ManagedVec<u64>, from: usize, size: usize) -> MultiValueEncoded<Offer> {
+        let mut result = MultiValueEncoded::new();
+        let offers = offer_ids
+            .iter()
+            .map(|offer_id| self.offers(&offer_id).get())
+            .filter(|offer| !self.is_offer_expired(offer))
+            .skip(from)
+            .take(size.min(shared::MAX_LISTINGS_PAGE_SIZE));
+        
+        for offer in offers {
+            result.push(offer);
+        }
+        
+        result
+    }
+
+    fn require_offer(&self, offer_id: u64) -> Offer {
+        require!(!self.offers(&offer_id).is_empty(), shared::ERROR_OFFER_NOT_FOUND);
+        self.offers(&offer_id).get()
+    }
+
+    fn remove_offer(&self, offer: &Offer) {
+        self.offers(&offer.id).clear();
+        self.user_offers(&offer.buyer).swap_remove(&offer.id);
+        match &offer.target {
+            OfferTarget::Hero(hero_id) => {
+                self.hero_offers(hero_id).swap_remove(&offer.id);
+            },
+            OfferTarget::Collection(_) => {
+                self.collection_offers().swap_remove(&offer.id);
+            },
+        }
+    }
+
+    fn is_offer_expired(&self, offer: &Offer) -> bool {
+        self.blockchain().get_block_timestamp() >= offer.expires_at
+    }
+
+    fn require_not_paused(&self);
+}
@@ -0,0 +1,133 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait PvPModule:
+    shared::StorageModule
+    + shared::EventsModule
+    + crate::hero_management::HeroManagementModule
+    + crate::battle::BattleModule
+{
+    // PvP Matchmaking
+    #[payable("EGLD")]
+    #[endpoint(joinPvPQueue)]
+    fn join_pvp_queue(&self, hero_id: u64) -> OptionalValue<u64> {
+        self.require_not_paused();
+        
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == BigUint::from(shared::PVP_MATCH_FEE), shared::ERROR_INVALID_PAYMENT);
+        
+        let caller = self.blockchain().get_caller();
+        self.require_hero_user(hero_id, &caller);
+        self.require_hero_available(hero_id);
+        
+        let power = self.hero_power(hero_id);
+        let band = self.pvp_power_band(power);
+        
+        // Pair with the closest queued opponent, otherwise wait in the queue
+        match self.find_pvp_opponent(&caller, power, band) {
+            Some(opponent) => {
+                let battle_id = self.create_pvp_match(opponent, hero_id, payment);
+                OptionalValue::Some(battle_id)
+            },
+            None => {
+                require!(
+                    self.pvp_queue_band(&band).len() < shared::PVP_QUEUE_BAND_CAPACITY,
+                    shared::ERROR_PVP_QUEUE_BAND_FULL
+                );
+                
+                self.pvp_queue().insert(hero_id);
+                self.pvp_queue_band(&band).insert(hero_id);
+                self.pvp_queue_entries(&hero_id).set(&PvPQueueEntry {
+                    player: caller.clone(),
+                    hero_id,
+                    power,
+                    fee: payment,
+                    joined_at: self.blockchain().get_block_timestamp(),
+                });
+                
+                self.pvp_queue_joined_event(&caller, hero_id, power);
+                OptionalValue::None
+            },
+        }
+    }
+
+    #[endpoint(leavePvPQueue)]
+    fn leave_pvp_queue(&self, hero_id: u64) {
+        require!(self.pvp_queue().contains(&hero_id), shared::ERROR_HERO_NOT_IN_QUEUE);
+        
+        let caller = self.blockchain().get_caller();
+        let entry = self.pvp_queue_entries(&hero_id).get();
+        require!(entry.player == caller, shared::ERROR_NOT_QUEUE_ENTRY_OWNER);
+        
+        self.remove_from_pvp_queue(&entry);
+        
+        self.send().direct_egld(&caller, &entry.fee);
+        self.pvp_queue_left_event(&caller, hero_id, &entry.fee);
+    }
+
+    // Matchmaking helpers
+    // Only the neighbouring power bands can hold a match, so joining never scans the whole queue
+    fn find_pvp_opponent(&self, player: &ManagedAddress, power: u64, band: u64) -> Option<PvPQueueEntry> {
+        let mut best_match: Option<PvPQueueEntry> = None;
+        let mut best_difference = u64::MAX;
+        
+        for nearby_band in band.saturating_sub(1)..=band + 1 {
+            for queued_hero_id in self.pvp_queue_band(&nearby_band).iter() {
+                let entry = self.pvp_queue_entries(&queued_hero_id).get();
+                if &entry.player == player {
+                    continue;
+                }
+                
+                let difference = power.abs_diff(entry.power);
+                let max_difference = core::cmp::max(power, entry.power) * shared::PVP_POWER_TOLERANCE_PERCENTAGE / 100;
+                if difference <= max_difference && difference < best_difference {
+                    best_difference = difference;
+                    best_match = Some(entry);
+                }
+            }
+        }
+        
+        best_match
+    }
+
+    // Bands grow geometrically by at least the power tolerance, so any valid opponent sits in the same or an adjacent band
+    fn pvp_power_band(&self, power: u64) -> u64 {
+        let mut band = 0u64;
+        let mut upper_bound = 1u64;
+        while upper_bound <= power {
+            upper_bound = upper_bound * 100 / (100 - shared::PVP_POWER_TOLERANCE_PERCENTAGE) + 1;
+            band += 1;
+        }
+        
+        band
+    }
+
+    fn remove_from_pvp_queue(&self, entry: &PvPQueueEntry) {
+        self.pvp_queue().swap_remove(&entry.hero_id);
+        self.pvp_queue_band(&self.pvp_power_band(entry.power)).swap_remove(&entry.hero_id);
+        self.pvp_queue_entries(&entry.hero_id).clear();
+    }
+
+    fn create_pvp_match(&self, opponent: PvPQueueEntry, hero_id: u64, payment: BigUint) -> u64 {
+        self.remove_from_pvp_queue(&opponent);
+        
+        let mut hero_ids = ManagedVec::new();
+        hero_ids.push(opponent.hero_id);
+        hero_ids.push(hero_id);
+        
+        let battle_id = self.start_matched_battle(BattleType::PvP, hero_ids);
+        
+        // Both match fees stay in the contract until the battle is resolved
+        self.battle_escrow(&battle_id).set(opponent.fee + payment);
+        
+        battle_id
+    }
+
+    fn hero_power(&self, hero_id: u64) -> u64 {
+        let combatant = self.build_combatant(&self.heroes(&hero_id).get());
+        combatant.attack + combatant.defense + combatant.speed + combatant.max_health / 10
+    }
+}
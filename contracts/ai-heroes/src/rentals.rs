@@ -0,0 +1,180 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait RentalModule:
+    shared::StorageModule
+    + shared::EventsModule
+    + crate::items::ItemsModule
+    + crate::hero_management::HeroManagementModule
+{
+    // Rental Listings
+    // A zero price with an owner share is a scholarship: free to use, rewards are split
+    #[endpoint(listHeroForRent)]
+    fn list_hero_for_rent(&self, hero_id: u64, price: BigUint, duration: u64, owner_share: u64) {
+        self.require_not_paused();
+        require!(
+            (shared::MIN_RENTAL_DURATION..=shared::MAX_RENTAL_DURATION).contains(&duration),
+            shared::ERROR_INVALID_RENTAL_DURATION
+        );
+        require!(owner_share <= shared::BASIS_POINTS_DIVISOR, shared::ERROR_INVALID_REVENUE_SHARE);
+        
+        let caller = self.blockchain().get_caller();
+        self.require_hero_owned_by(hero_id, &caller);
+        self.require_hero_available(hero_id);
+        
+        self.rental_listings(&hero_id).set(&RentalListing {
+            owner: caller.clone(),
+            price: price.clone(),
+            duration,
+            owner_share,
+        });
+        self.rental_listed_heroes().insert(hero_id);
+        
+        self.hero_listed_for_rent_event(hero_id, &caller, &price, duration, owner_share);
+    }
+
+    #[endpoint(cancelRentalListing)]
+    fn cancel_rental_listing(&self, hero_id: u64) {
+        let listing = self.require_rental_listing(hero_id);
+        let caller = self.blockchain().get_caller();
+        require!(listing.owner == caller, shared::ERROR_HERO_NOT_OWNED);
+        
+        self.remove_rental_listing(hero_id);
+        self.rental_listing_cancelled_event(hero_id, &caller);
+    }
+
+    // Renting
+    #[payable("EGLD")]
+    #[endpoint(rentHero)]
+    fn rent_hero(&self, hero_id: u64) {
+        self.require_not_paused();
+        
+        let listing = self.require_rental_listing(hero_id);
+        require!(
+            self.hero_owners(&hero_id).get() == listing.owner,
+            shared::ERROR_ASSET_NOT_OWNED_BY_SELLER
+        );
+        self.require_hero_available(hero_id);
+        self.require_hero_not_rented(hero_id);
+        
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == listing.price, shared::ERROR_INVALID_PAYMENT);
+        
+        let renter = self.blockchain().get_caller();
+        require!(renter != listing.owner, shared::ERROR_CANNOT_BUY_OWN_ASSET);
+        
+        // Clean up the previous rental if it was never ended explicitly
+        if !self.hero_rentals(&hero_id).is_empty() {
+            self.release_rental(hero_id);
+        }
+        
+        let now = self.blockchain().get_block_timestamp();
+        let expires_at = now + listing.duration;
+        self.hero_rentals(&hero_id).set(&Rental {
+            owner: listing.owner.clone(),
+            renter: renter.clone(),
+            price: payment.clone(),
+            owner_share: listing.owner_share,
+            started_at: now,
+            expires_at,
+        });
+        self.user_rentals(&renter).insert(hero_id);
+        self.remove_rental_listing(hero_id);
+        
+        let rental_fee = &payment * shared::MARKETPLACE_FEE_PERCENTAGE / shared::BASIS_POINTS_DIVISOR;
+        if rental_fee > 0 {
+            self.add_revenue(&ManagedBuffer::from(b"rental"), &rental_fee);
+        }
+        self.send().direct_non_zero_egld(&listing.owner, &(&payment - &rental_fee));
+        
+        self.hero_rented_event(hero_id, &listing.owner, &renter, &payment, expires_at);
+    }
+
+    // The hero already returns to its owner at expiry; this clears the record
+    // and unequips the renter's items. Anyone can call it.
+    #[endpoint(endRental)]
+    fn end_rental(&self, hero_id: u64) {
+        require!(!self.hero_rentals(&hero_id).is_empty(), shared::ERROR_RENTAL_NOT_FOUND);
+        require!(self.active_rental(hero_id).is_none(), shared::ERROR_RENTAL_NOT_EXPIRED);
+        self.require_hero_available(hero_id);
+        
+        self.release_rental(hero_id);
+    }
+
+    // Views
+    #[view(getHeroUser)]
+    fn get_hero_user(&self, hero_id: u64) -> ManagedAddress {
+        require!(!self.heroes(&hero_id).is_empty(), shared::ERROR_HERO_NOT_FOUND);
+        self.hero_user(hero_id)
+    }
+
+    #[view(getRentedHeroes)]
+    fn get_rented_heroes(&self, renter: ManagedAddress) -> MultiValueEncoded<u64> {
+        let mut result = MultiValueEncoded::new();
+        for hero_id in self.user_rentals(&renter).iter() {
+            if self.active_rental(hero_id).is_some() {
+                result.push(hero_id);
+            }
+        }
+        
+        result
+    }
+
+    #[view(getRentalListings)]
+    fn get_rental_listings(&self, from: usize, size: usize) -> MultiValueEncoded<MultiValue2<u64, RentalListing>> {
+        let mut result = MultiValueEncoded::new();
+        let hero_ids = self
+            .rental_listed_heroes()
+            .iter()
+            .skip(from)
+            .take(size.min(shared::MAX_LISTINGS_PAGE_SIZE));
+        
+        for hero_id in hero_ids {
+            result.push((hero_id, self.rental_listings(&hero_id).get()).into());
+        }
+        
+        result
+    }
+
+    // Rental helpers
+    fn release_rental(&self, hero_id: u64) {
+        let rental = self.hero_rentals(&hero_id).take();
+        self.user_rentals(&rental.renter).swap_remove(&hero_id);
+        
+        let mut hero = self.heroes(&hero_id).get();
+        let slots = [
+            &mut hero.equipment.weapon,
+            &mut hero.equipment.armor,
+            &mut hero.equipment.helmet,
+            &mut hero.equipment.boots,
+            &mut hero.equipment.accessory,
+        ];
+        for slot in slots {
+            if let Some(item_id) = *slot {
+                if self.item_owners(&item_id).get() == rental.renter {
+                    self.item_equipped_on(&item_id).clear();
+                    *slot = None;
+                }
+            }
+        }
+        self.heroes(&hero_id).set(&hero);
+        
+        self.rental_ended_event(hero_id, &rental.owner, &rental.renter);
+    }
+
+    fn require_rental_listing(&self, hero_id: u64) -> RentalListing {
+        require!(!self.rental_listings(&hero_id).is_empty(), shared::ERROR_RENTAL_LISTING_NOT_FOUND);
+        self.rental_listings(&hero_id).get()
+    }
+
+    fn remove_rental_listing(&self, hero_id: u64) {
+        self.rental_listings(&hero_id).clear();
+        self.rental_listed_heroes().swap_remove(&hero_id);
+    }
+
+    fn require_not_paused(&self);
+    fn add_revenue(&self, fee_type: &ManagedBuffer, amount: &BigUint);
+}
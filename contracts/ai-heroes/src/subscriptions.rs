@@ -0,0 +1,118 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait SubscriptionModule:
+    shared::StorageModule
+    + shared::EventsModule
+{
+    // Subscriptions
+    // The payment must cover a whole number of months of the chosen plan
+    #[payable("EGLD")]
+    #[endpoint(subscribe)]
+    fn subscribe(&self, plan: ManagedBuffer) -> u64 {
+        self.require_not_paused();
+        
+        let caller = self.blockchain().get_caller();
+        require!(!self.has_active_subscription(&caller), shared::ERROR_SUBSCRIPTION_ALREADY_ACTIVE);
+        
+        let now = self.blockchain().get_block_timestamp();
+        self.subscription_types(&caller).set(&plan);
+        self.extend_subscription(&caller, &plan, now)
+    }
+
+    // Stacks on top of the current expiry, or starts again from now once it has lapsed
+    #[payable("EGLD")]
+    #[endpoint(renewSubscription)]
+    fn renew_subscription(&self) -> u64 {
+        self.require_not_paused();
+        
+        let caller = self.blockchain().get_caller();
+        require!(!self.subscription_types(&caller).is_empty(), shared::ERROR_SUBSCRIPTION_NOT_FOUND);
+        
+        let plan = self.subscription_types(&caller).get();
+        let now = self.blockchain().get_block_timestamp();
+        let start = core::cmp::max(self.subscriptions(&caller).get(), now);
+        self.extend_subscription(&caller, &plan, start)
+    }
+
+    // Views
+    #[view(getSubscriptionStatus)]
+    fn get_subscription_status(&self, user: ManagedAddress) -> MultiValue3<ManagedBuffer, u64, bool> {
+        (
+            self.subscription_types(&user).get(),
+            self.subscriptions(&user).get(),
+            self.has_active_subscription(&user),
+        )
+            .into()
+    }
+
+    #[view(isSubscriptionActive)]
+    fn is_subscription_active(&self, user: ManagedAddress) -> bool {
+        self.has_active_subscription(&user)
+    }
+
+    #[view(getSubscriptionPlanFee)]
+    fn get_subscription_plan_fee(&self, plan: ManagedBuffer) -> BigUint {
+        self.subscription_plan_fee(&plan)
+    }
+
+    #[view(getEvolutionFee)]
+    fn get_evolution_fee(&self, user: ManagedAddress) -> BigUint {
+        self.evolution_fee(&user)
+    }
+
+    // Subscription helpers
+    fn extend_subscription(&self, user: &ManagedAddress, plan: &ManagedBuffer, start: u64) -> u64 {
+        let monthly_fee = self.subscription_plan_fee(plan);
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment > 0 && &payment % &monthly_fee == 0, shared::ERROR_INVALID_PAYMENT);
+        
+        let months = (&payment / &monthly_fee)
+            .to_u64()
+            .unwrap_or_else(|| sc_panic!(shared::ERROR_INVALID_PAYMENT));
+        let duration = months * shared::SECONDS_PER_MONTH;
+        let expiry = start + duration;
+        self.subscriptions(user).set(expiry);
+        
+        self.add_revenue(&ManagedBuffer::from(b"subscription"), &payment);
+        self.subscription_activated_event(user, plan, duration, &payment);
+        
+        expiry
+    }
+
+    fn subscription_plan_fee(&self, plan: &ManagedBuffer) -> BigUint {
+        if plan == &ManagedBuffer::from(shared::AI_COMPANION_PLAN) {
+            BigUint::from(shared::AI_COMPANION_MONTHLY_FEE)
+        } else if plan == &ManagedBuffer::from(shared::PREMIUM_PLAN) {
+            BigUint::from(shared::PREMIUM_MONTHLY_FEE)
+        } else {
+            sc_panic!(shared::ERROR_INVALID_SUBSCRIPTION_TYPE)
+        }
+    }
+
+    fn has_active_subscription(&self, user: &ManagedAddress) -> bool {
+        self.subscriptions(user).get() > self.blockchain().get_block_timestamp()
+    }
+
+    fn has_ai_companion_plan(&self, user: &ManagedAddress) -> bool {
+        self.has_active_subscription(user)
+            && self.subscription_types(user).get() == ManagedBuffer::from(shared::AI_COMPANION_PLAN)
+    }
+
+    // Any active plan discounts the evolution fee
+    fn evolution_fee(&self, user: &ManagedAddress) -> BigUint {
+        let fee = BigUint::from(shared::NFT_EVOLUTION_FEE);
+        if self.has_active_subscription(user) {
+            let discount = &fee * shared::SUBSCRIBER_EVOLUTION_DISCOUNT / shared::BASIS_POINTS_DIVISOR;
+            fee - discount
+        } else {
+            fee
+        }
+    }
+
+    fn require_not_paused(&self);
+    fn add_revenue(&self, fee_type: &ManagedBuffer, amount: &BigUint);
+}
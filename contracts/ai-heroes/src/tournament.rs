@@ -0,0 +1,245 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use shared::*;
+
+#[multiversx_sc::module]
+pub trait TournamentModule:
+    shared::StorageModule
+    + shared::EventsModule
+    + crate::hero_management::HeroManagementModule
+    + crate::battle::BattleModule
+{
+    // Tournament Administration
+    #[only_owner]
+    #[endpoint(createTournament)]
+    fn create_tournament(
+        &self,
+        name: ManagedBuffer,
+        size: u32,
+        start_time: u64,
+        prize_split: MultiValueEncoded<u64>,
+    ) -> u64 {
+        require!(
+            size >= shared::MIN_TOURNAMENT_SIZE && size <= shared::MAX_TOURNAMENT_SIZE && size.is_power_of_two(),
+            shared::ERROR_INVALID_TOURNAMENT_SIZE
+        );
+        require!(start_time > self.blockchain().get_block_timestamp(), shared::ERROR_INVALID_START_TIME);
+        
+        let prize_split = prize_split.to_vec();
+        let total_split: u64 = prize_split.iter().sum();
+        require!(
+            !prize_split.is_empty() && prize_split.len() <= size as usize && total_split == shared::BASIS_POINTS_DIVISOR,
+            shared::ERROR_INVALID_PRIZE_SPLIT
+        );
+        
+        let tournament_id = self.tournament_count().get() + 1;
+        self.tournament_count().set(tournament_id);
+        
+        let entry_fee = BigUint::from(shared::TOURNAMENT_ENTRY_FEE);
+        self.tournament_created_event(tournament_id, size, start_time, &entry_fee);
+        
+        self.tournaments(&tournament_id).set(&Tournament {
+            id: tournament_id,
+            name,
+            size,
+            entry_fee,
+            start_time,
+            prize_split,
+            status: TournamentStatus::Registration,
+            current_round: 0,
+            prize_pool: BigUint::zero(),
+        });
+        
+        tournament_id
+    }
+
+    // Owner can cancel during registration; anyone can once the start time passes without a full bracket
+    #[endpoint(cancelTournament)]
+    fn cancel_tournament(&self, tournament_id: u64) {
+        let mut tournament = self.require_tournament(tournament_id);
+        require!(tournament.status == TournamentStatus::Registration, shared::ERROR_TOURNAMENT_CANNOT_CANCEL);
+        
+        let caller = self.blockchain().get_caller();
+        let registered = self.tournament_heroes(&tournament_id).len() as u32;
+        let expired_unfilled = self.blockchain().get_block_timestamp() >= tournament.start_time
+            && registered < tournament.size;
+        require!(caller == self.owner().get() || expired_unfilled, shared::ERROR_TOURNAMENT_CANNOT_CANCEL);
+        
+        for hero_id in self.tournament_heroes(&tournament_id).iter() {
+            self.hero_tournament(&hero_id).clear();
+            self.send().direct_egld(&self.tournament_entrants(&tournament_id, &hero_id).get(), &tournament.entry_fee);
+        }
+        
+        tournament.status = TournamentStatus::Cancelled;
+        tournament.prize_pool = BigUint::zero();
+        self.tournaments(&tournament_id).set(&tournament);
+        
+        self.tournament_cancelled_event(tournament_id, registered);
+    }
+
+    // Registration
+    #[payable("EGLD")]
+    #[endpoint(registerForTournament)]
+    fn register_for_tournament(&self, tournament_id: u64, hero_id: u64) {
+        self.require_not_paused();
+        
+        let mut tournament = self.require_tournament(tournament_id);
+        require!(
+            tournament.status == TournamentStatus::Registration
+                && self.blockchain().get_block_timestamp() < tournament.start_time,
+            shared::ERROR_TOURNAMENT_REGISTRATION_CLOSED
+        );
+        require!(
+            (self.tournament_heroes(&tournament_id).len() as u32) < tournament.size,
+            shared::ERROR_TOURNAMENT_FULL
+        );
+        
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == tournament.entry_fee, shared::ERROR_INVALID_PAYMENT);
+        
+        let caller = self.blockchain().get_caller();
+        self.require_hero_user(hero_id, &caller);
+        self.require_hero_available(hero_id);
+        
+        self.tournament_heroes(&tournament_id).push(&hero_id);
+        self.hero_tournament(&hero_id).set(tournament_id);
+        self.tournament_entrants(&tournament_id, &hero_id).set(&caller);
+        
+        tournament.prize_pool += payment;
+        self.tournaments(&tournament_id).set(&tournament);
+    }
+
+    // Bracket Progression
+    #[endpoint(startTournament)]
+    fn start_tournament(&self, tournament_id: u64) {
+        self.require_not_paused();
+        
+        let mut tournament = self.require_tournament(tournament_id);
+        require!(tournament.status == TournamentStatus::Registration, shared::ERROR_TOURNAMENT_REGISTRATION_CLOSED);
+        require!(
+            self.blockchain().get_block_timestamp() >= tournament.start_time,
+            shared::ERROR_TOURNAMENT_NOT_STARTED
+        );
+        require!(
+            self.tournament_heroes(&tournament_id).len() as u32 == tournament.size,
+            shared::ERROR_TOURNAMENT_NOT_FULL
+        );
+        
+        let mut hero_ids = ManagedVec::new();
+        for hero_id in self.tournament_heroes(&tournament_id).iter() {
+            hero_ids.push(hero_id);
+        }
+        
+        tournament.status = TournamentStatus::InProgress;
+        self.start_tournament_round(&mut tournament, &hero_ids);
+        self.tournaments(&tournament_id).set(&tournament);
+    }
+
+    // Anyone can advance the bracket once every battle of the current round is resolved
+    #[endpoint(advanceTournament)]
+    fn advance_tournament(&self, tournament_id: u64) {
+        self.require_not_paused();
+        
+        let mut tournament = self.require_tournament(tournament_id);
+        require!(tournament.status == TournamentStatus::InProgress, shared::ERROR_TOURNAMENT_NOT_IN_PROGRESS);
+        
+        let round = tournament.current_round;
+        let mut winners = ManagedVec::new();
+        for battle_id in self.tournament_round_battles(&tournament_id, &round).iter() {
+            let battle = self.battles(&battle_id).get();
+            require!(battle.status == BattleStatus::Completed, shared::ERROR_TOURNAMENT_ROUND_IN_PROGRESS);
+            
+            let winner_hero_id = self.battle_winner_hero(&battle_id).get();
+            winners.push(winner_hero_id);
+            
+            // Eliminated heroes are released straight away
+            for hero_id in battle.hero_ids.iter() {
+                if hero_id != winner_hero_id {
+                    self.hero_tournament(&hero_id).clear();
+                }
+            }
+        }
+        
+        if winners.len() > 1 {
+            self.start_tournament_round(&mut tournament, &winners);
+        } else {
+            let champion_hero_id = winners.get(0);
+            self.hero_tournament(&champion_hero_id).clear();
+            self.record_tournament_placements(&tournament, champion_hero_id);
+            self.distribute_tournament_prizes(&tournament);
+            
+            tournament.status = TournamentStatus::Completed;
+            self.tournament_completed_event(tournament_id, champion_hero_id, &tournament.prize_pool);
+        }
+        
+        self.tournaments(&tournament_id).set(&tournament);
+    }
+
+    // Tournament helpers
+    fn require_tournament(&self, tournament_id: u64) -> Tournament {
+        require!(!self.tournaments(&tournament_id).is_empty(), shared::ERROR_TOURNAMENT_NOT_FOUND);
+        self.tournaments(&tournament_id).get()
+    }
+
+    fn start_tournament_round(&self, tournament: &mut Tournament, hero_ids: &ManagedVec<u64>) {
+        tournament.current_round += 1;
+        let round = tournament.current_round;
+        let battle_count = hero_ids.len() / 2;
+        
+        for pair_index in 0..battle_count {
+            let mut pair = ManagedVec::new();
+            pair.push(hero_ids.get(pair_index * 2));
+            pair.push(hero_ids.get(pair_index * 2 + 1));
+            
+            let battle_id = self.start_matched_battle(BattleType::Tournament, pair);
+            self.tournament_round_battles(&tournament.id, &round).push(&battle_id);
+        }
+        
+        self.tournament_round_started_event(tournament.id, round, battle_count as u32);
+    }
+
+    // Champion first, then the losers of each round from the final backwards
+    fn record_tournament_placements(&self, tournament: &Tournament, champion_hero_id: u64) {
+        let placements_needed = tournament.prize_split.len();
+        let mut placements = self.tournament_placements(&tournament.id);
+        placements.push(&champion_hero_id);
+        
+        for round in (1..=tournament.current_round).rev() {
+            for battle_id in self.tournament_round_battles(&tournament.id, &round).iter() {
+                if placements.len() >= placements_needed {
+                    return;
+                }
+                
+                let winner_hero_id = self.battle_winner_hero(&battle_id).get();
+                for hero_id in self.battles(&battle_id).get().hero_ids.iter() {
+                    if hero_id != winner_hero_id {
+                        placements.push(&hero_id);
+                    }
+                }
+            }
+        }
+    }
+
+    fn distribute_tournament_prizes(&self, tournament: &Tournament) {
+        let platform_fee = &tournament.prize_pool * shared::TOURNAMENT_FEE_PERCENTAGE / shared::BASIS_POINTS_DIVISOR;
+        let prizes = &tournament.prize_pool - &platform_fee;
+        let mut distributed = BigUint::zero();
+        
+        for (index, share) in tournament.prize_split.iter().enumerate() {
+            let hero_id = self.tournament_placements(&tournament.id).get(index + 1);
+            let prize = &prizes * share / shared::BASIS_POINTS_DIVISOR;
+            if prize > 0 {
+                let entrant = self.tournament_entrants(&tournament.id, &hero_id).get();
+                self.pay_hero_reward(hero_id, &entrant, &prize);
+                distributed += &prize;
+            }
+        }
+        
+        // Rounding dust stays with the platform cut
+        let revenue = platform_fee + (prizes - distributed);
+        self.add_revenue(&ManagedBuffer::from(b"tournament"), &revenue);
+    }
+
+    fn add_revenue(&self, fee_type: &ManagedBuffer, amount: &BigUint);
+}
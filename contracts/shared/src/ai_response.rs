@@ -0,0 +1,494 @@
+use crate::constants::*;
+use crate::errors::ERROR_AI_RESPONSE_INVALID;
+use crate::types::*;
+
+// Decoder for oracle answers to HeroGeneration requests.
+// Two formats are accepted:
+// - the fixed binary layout of AIHeroGenerationResult (class, 6 stats, battle style,
+//   adaptation rate, learning factor; integers big-endian, enums as one-byte indexes)
+// - a restricted JSON object: no escapes, no floats, no unknown or duplicate keys, e.g.
+//   {"class":"Mage","battle_style":"Tactical","adaptation_rate":80,"learning_factor":75,
+//    "stats":{"strength":95,"intelligence":130,"agility":100,"vitality":100,"luck":110,"magic_power":120}}
+
+pub const AI_HERO_BINARY_RESPONSE_LENGTH: usize = 34;
+
+type DecodeResult<T> = Result<T, &'static str>;
+
+pub fn decode_hero_generation_response(data: &[u8]) -> DecodeResult<AIHeroGenerationResult> {
+    if data.is_empty() || data.len() > MAX_AI_RESPONSE_LENGTH {
+        return Err(ERROR_AI_RESPONSE_INVALID);
+    }
+
+    let starts_with_brace = data
+        .iter()
+        .find(|byte| !is_json_whitespace(**byte))
+        .is_some_and(|byte| *byte == b'{');
+
+    let result = if starts_with_brace {
+        decode_json(data)?
+    } else {
+        decode_binary(data)?
+    };
+
+    validate_hero_generation_result(&result)?;
+    Ok(result)
+}
+
+pub fn validate_hero_generation_result(result: &AIHeroGenerationResult) -> DecodeResult<()> {
+    let stats = [
+        result.stats.strength,
+        result.stats.intelligence,
+        result.stats.agility,
+        result.stats.vitality,
+        result.stats.luck,
+        result.stats.magic_power,
+    ];
+
+    let mut total = 0u32;
+    for stat in stats {
+        if !(MIN_AI_STAT_VALUE..=MAX_AI_STAT_VALUE).contains(&stat) {
+            return Err(ERROR_AI_RESPONSE_INVALID);
+        }
+        total += stat;
+    }
+
+    if total > MAX_AI_STAT_TOTAL
+        || result.adaptation_rate > MAX_AI_TRAIT_VALUE
+        || result.learning_factor > MAX_AI_TRAIT_VALUE
+    {
+        return Err(ERROR_AI_RESPONSE_INVALID);
+    }
+
+    Ok(())
+}
+
+// Binary layout
+fn decode_binary(data: &[u8]) -> DecodeResult<AIHeroGenerationResult> {
+    if data.len() != AI_HERO_BINARY_RESPONSE_LENGTH {
+        return Err(ERROR_AI_RESPONSE_INVALID);
+    }
+
+    Ok(AIHeroGenerationResult {
+        class: hero_class_from_index(data[0])?,
+        stats: HeroStats {
+            strength: read_u32(data, 1),
+            intelligence: read_u32(data, 5),
+            agility: read_u32(data, 9),
+            vitality: read_u32(data, 13),
+            luck: read_u32(data, 17),
+            magic_power: read_u32(data, 21),
+        },
+        battle_style: battle_style_from_index(data[25])?,
+        adaptation_rate: read_u32(data, 26),
+        learning_factor: read_u32(data, 30),
+    })
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([data[offset], data[offset + 1], data[offset + 2], data[offset + 3]])
+}
+
+fn hero_class_from_index(index: u8) -> DecodeResult<HeroClass> {
+    match index {
+        0 => Ok(HeroClass::Warrior),
+        1 => Ok(HeroClass::Mage),
+        2 => Ok(HeroClass::Rogue),
+        3 => Ok(HeroClass::Paladin),
+        4 => Ok(HeroClass::Necromancer),
+        5 => Ok(HeroClass::Elementalist),
+        _ => Err(ERROR_AI_RESPONSE_INVALID),
+    }
+}
+
+fn battle_style_from_index(index: u8) -> DecodeResult<BattleStyle> {
+    match index {
+        0 => Ok(BattleStyle::Aggressive),
+        1 => Ok(BattleStyle::Defensive),
+        2 => Ok(BattleStyle::Balanced),
+        3 => Ok(BattleStyle::Tactical),
+        4 => Ok(BattleStyle::Berserker),
+        _ => Err(ERROR_AI_RESPONSE_INVALID),
+    }
+}
+
+// Restricted JSON
+fn decode_json(data: &[u8]) -> DecodeResult<AIHeroGenerationResult> {
+    let mut parser = JsonParser { data, position: 0 };
+    let mut class = None;
+    let mut battle_style = None;
+    let mut stats = None;
+    let mut adaptation_rate = None;
+    let mut learning_factor = None;
+
+    parser.expect(b'{')?;
+    loop {
+        let key = parser.parse_string()?;
+        parser.expect(b':')?;
+        match key {
+            b"class" => set_once(&mut class, hero_class_from_name(parser.parse_string()?)?)?,
+            b"battle_style" => set_once(&mut battle_style, battle_style_from_name(parser.parse_string()?)?)?,
+            b"stats" => set_once(&mut stats, parser.parse_stats()?)?,
+            b"adaptation_rate" => set_once(&mut adaptation_rate, parser.parse_u32()?)?,
+            b"learning_factor" => set_once(&mut learning_factor, parser.parse_u32()?)?,
+            _ => return Err(ERROR_AI_RESPONSE_INVALID),
+        }
+
+        if !parser.consume(b',') {
+            break;
+        }
+    }
+    parser.expect(b'}')?;
+    parser.expect_end()?;
+
+    Ok(AIHeroGenerationResult {
+        class: class.ok_or(ERROR_AI_RESPONSE_INVALID)?,
+        stats: stats.ok_or(ERROR_AI_RESPONSE_INVALID)?,
+        battle_style: battle_style.ok_or(ERROR_AI_RESPONSE_INVALID)?,
+        adaptation_rate: adaptation_rate.ok_or(ERROR_AI_RESPONSE_INVALID)?,
+        learning_factor: learning_factor.ok_or(ERROR_AI_RESPONSE_INVALID)?,
+    })
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T) -> DecodeResult<()> {
+    if slot.is_some() {
+        return Err(ERROR_AI_RESPONSE_INVALID);
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+fn hero_class_from_name(name: &[u8]) -> DecodeResult<HeroClass> {
+    let classes = [
+        (&b"warrior"[..], HeroClass::Warrior),
+        (&b"mage"[..], HeroClass::Mage),
+        (&b"rogue"[..], HeroClass::Rogue),
+        (&b"paladin"[..], HeroClass::Paladin),
+        (&b"necromancer"[..], HeroClass::Necromancer),
+        (&b"elementalist"[..], HeroClass::Elementalist),
+    ];
+
+    classes
+        .into_iter()
+        .find(|(class_name, _)| class_name.eq_ignore_ascii_case(name))
+        .map(|(_, class)| class)
+        .ok_or(ERROR_AI_RESPONSE_INVALID)
+}
+
+fn battle_style_from_name(name: &[u8]) -> DecodeResult<BattleStyle> {
+    let styles = [
+        (&b"aggressive"[..], BattleStyle::Aggressive),
+        (&b"defensive"[..], BattleStyle::Defensive),
+        (&b"balanced"[..], BattleStyle::Balanced),
+        (&b"tactical"[..], BattleStyle::Tactical),
+        (&b"berserker"[..], BattleStyle::Berserker),
+    ];
+
+    styles
+        .into_iter()
+        .find(|(style_name, _)| style_name.eq_ignore_ascii_case(name))
+        .map(|(_, style)| style)
+        .ok_or(ERROR_AI_RESPONSE_INVALID)
+}
+
+fn is_json_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r')
+}
+
+struct JsonParser<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.position < self.data.len() && is_json_whitespace(self.data[self.position]) {
+            self.position += 1;
+        }
+    }
+
+    fn consume(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        if self.position < self.data.len() && self.data[self.position] == byte {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, byte: u8) -> DecodeResult<()> {
+        if self.consume(byte) {
+            Ok(())
+        } else {
+            Err(ERROR_AI_RESPONSE_INVALID)
+        }
+    }
+
+    fn expect_end(&mut self) -> DecodeResult<()> {
+        self.skip_whitespace();
+        if self.position == self.data.len() {
+            Ok(())
+        } else {
+            Err(ERROR_AI_RESPONSE_INVALID)
+        }
+    }
+
+    // Plain ASCII strings only, escape sequences are rejected
+    fn parse_string(&mut self) -> DecodeResult<&'a [u8]> {
+        self.expect(b'"')?;
+        let start = self.position;
+        while self.position < self.data.len() {
+            match self.data[self.position] {
+                b'"' => {
+                    let value = &self.data[start..self.position];
+                    self.position += 1;
+                    return Ok(value);
+                },
+                b'\\' => return Err(ERROR_AI_RESPONSE_INVALID),
+                byte if !(0x20..=0x7E).contains(&byte) => return Err(ERROR_AI_RESPONSE_INVALID),
+                _ => self.position += 1,
+            }
+        }
+        Err(ERROR_AI_RESPONSE_INVALID)
+    }
+
+    // Unsigned integers without sign, fraction, exponent or leading zeros
+    fn parse_u32(&mut self) -> DecodeResult<u32> {
+        self.skip_whitespace();
+        let start = self.position;
+        let mut value = 0u32;
+        while self.position < self.data.len() && self.data[self.position].is_ascii_digit() {
+            let digit = (self.data[self.position] - b'0') as u32;
+            value = value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(digit))
+                .ok_or(ERROR_AI_RESPONSE_INVALID)?;
+            self.position += 1;
+        }
+
+        let length = self.position - start;
+        if length == 0 || (length > 1 && self.data[start] == b'0') {
+            return Err(ERROR_AI_RESPONSE_INVALID);
+        }
+        Ok(value)
+    }
+
+    fn parse_stats(&mut self) -> DecodeResult<HeroStats> {
+        let mut strength = None;
+        let mut intelligence = None;
+        let mut agility = None;
+        let mut vitality = None;
+        let mut luck = None;
+        let mut magic_power = None;
+
+        self.expect(b'{')?;
+        loop {
+            let key = self.parse_string()?;
+            self.expect(b':')?;
+            let value = self.parse_u32()?;
+            match key {
+                b"strength" => set_once(&mut strength, value)?,
+                b"intelligence" => set_once(&mut intelligence, value)?,
+                b"agility" => set_once(&mut agility, value)?,
+                b"vitality" => set_once(&mut vitality, value)?,
+                b"luck" => set_once(&mut luck, value)?,
+                b"magic_power" => set_once(&mut magic_power, value)?,
+                _ => return Err(ERROR_AI_RESPONSE_INVALID),
+            }
+
+            if !self.consume(b',') {
+                break;
+            }
+        }
+        self.expect(b'}')?;
+
+        Ok(HeroStats {
+            strength: strength.ok_or(ERROR_AI_RESPONSE_INVALID)?,
+            intelligence: intelligence.ok_or(ERROR_AI_RESPONSE_INVALID)?,
+            agility: agility.ok_or(ERROR_AI_RESPONSE_INVALID)?,
+            vitality: vitality.ok_or(ERROR_AI_RESPONSE_INVALID)?,
+            luck: luck.ok_or(ERROR_AI_RESPONSE_INVALID)?,
+            magic_power: magic_power.ok_or(ERROR_AI_RESPONSE_INVALID)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_STATS: [u32; 6] = [95, 130, 100, 100, 110, 120];
+
+    const VALID_JSON: &[u8] = br#"{"class":"Mage","battle_style":"Tactical","adaptation_rate":80,"learning_factor":75,
+        "stats":{"strength":95,"intelligence":130,"agility":100,"vitality":100,"luck":110,"magic_power":120}}"#;
+
+    fn binary_response(
+        class: u8,
+        stats: [u32; 6],
+        battle_style: u8,
+        adaptation_rate: u32,
+        learning_factor: u32,
+    ) -> [u8; AI_HERO_BINARY_RESPONSE_LENGTH] {
+        let mut data = [0u8; AI_HERO_BINARY_RESPONSE_LENGTH];
+        data[0] = class;
+        for (index, stat) in stats.iter().enumerate() {
+            data[1 + index * 4..5 + index * 4].copy_from_slice(&stat.to_be_bytes());
+        }
+        data[25] = battle_style;
+        data[26..30].copy_from_slice(&adaptation_rate.to_be_bytes());
+        data[30..34].copy_from_slice(&learning_factor.to_be_bytes());
+        data
+    }
+
+    fn assert_rejected(data: &[u8]) {
+        assert_eq!(decode_hero_generation_response(data), Err(ERROR_AI_RESPONSE_INVALID));
+    }
+
+    fn expected_result() -> AIHeroGenerationResult {
+        AIHeroGenerationResult {
+            class: HeroClass::Mage,
+            stats: HeroStats {
+                strength: 95,
+                intelligence: 130,
+                agility: 100,
+                vitality: 100,
+                luck: 110,
+                magic_power: 120,
+            },
+            battle_style: BattleStyle::Tactical,
+            adaptation_rate: 80,
+            learning_factor: 75,
+        }
+    }
+
+    // Valid input
+    #[test]
+    fn decodes_json() {
+        assert_eq!(decode_hero_generation_response(VALID_JSON), Ok(expected_result()));
+    }
+
+    #[test]
+    fn decodes_json_in_any_key_order_and_case() {
+        let data = br#"  { "learning_factor" : 75, "stats" : { "magic_power":120, "luck":110, "vitality":100,
+            "agility":100, "intelligence":130, "strength":95 }, "battle_style":"TACTICAL",
+            "adaptation_rate":80, "class":"mage" }  "#;
+        assert_eq!(decode_hero_generation_response(data), Ok(expected_result()));
+    }
+
+    #[test]
+    fn decodes_binary() {
+        let data = binary_response(1, VALID_STATS, 3, 80, 75);
+        assert_eq!(decode_hero_generation_response(&data), Ok(expected_result()));
+    }
+
+    // Bad class or battle style
+    #[test]
+    fn rejects_unknown_class() {
+        assert_rejected(br#"{"class":"Bard","battle_style":"Tactical","adaptation_rate":80,"learning_factor":75,
+            "stats":{"strength":95,"intelligence":130,"agility":100,"vitality":100,"luck":110,"magic_power":120}}"#);
+        assert_rejected(&binary_response(6, VALID_STATS, 3, 80, 75));
+    }
+
+    #[test]
+    fn rejects_unknown_battle_style() {
+        assert_rejected(br#"{"class":"Mage","battle_style":"Sneaky","adaptation_rate":80,"learning_factor":75,
+            "stats":{"strength":95,"intelligence":130,"agility":100,"vitality":100,"luck":110,"magic_power":120}}"#);
+        assert_rejected(&binary_response(1, VALID_STATS, 5, 80, 75));
+    }
+
+    // Out-of-range values
+    #[test]
+    fn rejects_stat_below_minimum() {
+        assert_rejected(&binary_response(1, [MIN_AI_STAT_VALUE - 1, 130, 100, 100, 110, 120], 3, 80, 75));
+    }
+
+    #[test]
+    fn rejects_stat_above_maximum() {
+        assert_rejected(&binary_response(1, [MAX_AI_STAT_VALUE + 1, 50, 50, 50, 50, 50], 3, 80, 75));
+        assert_rejected(br#"{"class":"Mage","battle_style":"Tactical","adaptation_rate":80,"learning_factor":75,
+            "stats":{"strength":201,"intelligence":50,"agility":50,"vitality":50,"luck":50,"magic_power":50}}"#);
+    }
+
+    #[test]
+    fn rejects_stat_total_above_budget() {
+        assert_rejected(&binary_response(1, [130; 6], 3, 80, 75));
+    }
+
+    #[test]
+    fn rejects_trait_above_maximum() {
+        assert_rejected(&binary_response(1, VALID_STATS, 3, MAX_AI_TRAIT_VALUE + 1, 75));
+        assert_rejected(&binary_response(1, VALID_STATS, 3, 80, MAX_AI_TRAIT_VALUE + 1));
+    }
+
+    // Truncated input
+    #[test]
+    fn rejects_empty_input() {
+        assert_rejected(b"");
+        assert_rejected(b"   ");
+    }
+
+    #[test]
+    fn rejects_truncated_binary() {
+        let data = binary_response(1, VALID_STATS, 3, 80, 75);
+        assert_rejected(&data[..AI_HERO_BINARY_RESPONSE_LENGTH - 1]);
+    }
+
+    #[test]
+    fn rejects_truncated_json() {
+        for length in [1, 10, 40, VALID_JSON.len() - 1] {
+            assert_rejected(&VALID_JSON[..length]);
+        }
+    }
+
+    #[test]
+    fn rejects_missing_field() {
+        assert_rejected(br#"{"class":"Mage","battle_style":"Tactical","adaptation_rate":80,
+            "stats":{"strength":95,"intelligence":130,"agility":100,"vitality":100,"luck":110,"magic_power":120}}"#);
+    }
+
+    // Oversized input and fields
+    #[test]
+    fn rejects_response_above_maximum_length() {
+        let mut data = [b' '; MAX_AI_RESPONSE_LENGTH + 1];
+        data[..VALID_JSON.len()].copy_from_slice(VALID_JSON);
+        assert_rejected(&data);
+    }
+
+    #[test]
+    fn rejects_oversized_binary() {
+        let mut data = [0u8; AI_HERO_BINARY_RESPONSE_LENGTH + 1];
+        data[..AI_HERO_BINARY_RESPONSE_LENGTH].copy_from_slice(&binary_response(1, VALID_STATS, 3, 80, 75));
+        assert_rejected(&data);
+    }
+
+    #[test]
+    fn rejects_number_overflowing_u32() {
+        assert_rejected(br#"{"class":"Mage","battle_style":"Tactical","adaptation_rate":4294967296,"learning_factor":75,
+            "stats":{"strength":95,"intelligence":130,"agility":100,"vitality":100,"luck":110,"magic_power":120}}"#);
+    }
+
+    // Restricted JSON syntax
+    #[test]
+    fn rejects_duplicate_and_unknown_keys() {
+        assert_rejected(br#"{"class":"Mage","class":"Mage","battle_style":"Tactical","adaptation_rate":80,"learning_factor":75,
+            "stats":{"strength":95,"intelligence":130,"agility":100,"vitality":100,"luck":110,"magic_power":120}}"#);
+        assert_rejected(br#"{"class":"Mage","battle_style":"Tactical","adaptation_rate":80,"learning_factor":75,"mood":1,
+            "stats":{"strength":95,"intelligence":130,"agility":100,"vitality":100,"luck":110,"magic_power":120}}"#);
+    }
+
+    #[test]
+    fn rejects_escapes_floats_and_leading_zeros() {
+        assert_rejected(br#"{"class":"M\u0061ge","battle_style":"Tactical","adaptation_rate":80,"learning_factor":75,
+            "stats":{"strength":95,"intelligence":130,"agility":100,"vitality":100,"luck":110,"magic_power":120}}"#);
+        assert_rejected(br#"{"class":"Mage","battle_style":"Tactical","adaptation_rate":80.5,"learning_factor":75,
+            "stats":{"strength":95,"intelligence":130,"agility":100,"vitality":100,"luck":110,"magic_power":120}}"#);
+        assert_rejected(br#"{"class":"Mage","battle_style":"Tactical","adaptation_rate":080,"learning_factor":75,
+            "stats":{"strength":95,"intelligence":130,"agility":100,"vitality":100,"luck":110,"magic_power":120}}"#);
+    }
+
+    #[test]
+    fn rejects_trailing_data() {
+        let mut data = [b'}'; 256];
+        data[..VALID_JSON.len()].copy_from_slice(VALID_JSON);
+        assert_rejected(&data[..VALID_JSON.len() + 1]);
+    }
+}
@@ -0,0 +1,416 @@
+use crate::constants::*;
+use crate::types::*;
+
+// Flattened battle view of a hero, built from its stats, equipment and battle style
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Combatant {
+    pub health: u64,
+    pub max_health: u64,
+    pub attack: u64,
+    pub defense: u64,
+    pub speed: u64,
+    pub crit_chance: u64, // basis points
+    pub life_steal: u64, // percentage of damage dealt
+    pub regeneration: u64, // health restored per round
+    pub resistance: u64, // percentage of damage ignored
+    pub level: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CombatOutcome {
+    pub winner: usize,
+    pub rounds: u32,
+    pub damage_dealt: [u64; MAX_BATTLE_PARTICIPANTS],
+    pub damage_taken: [u64; MAX_BATTLE_PARTICIPANTS],
+}
+
+impl Combatant {
+    pub fn new(stats: &HeroStats, level: u32, battle_style: &BattleStyle) -> Self {
+        let strength = stats.strength as u64;
+        let magic_power = stats.magic_power as u64;
+        let primary = core::cmp::max(strength, magic_power);
+        let secondary = core::cmp::min(strength, magic_power);
+
+        let mut combatant = Combatant {
+            health: stats.vitality as u64 * 10 + level as u64 * 20,
+            max_health: 0,
+            attack: primary * 2 + (secondary + stats.intelligence as u64) / 2,
+            defense: stats.vitality as u64 + (strength + stats.intelligence as u64) / 4,
+            speed: stats.agility as u64,
+            crit_chance: core::cmp::min(stats.luck as u64 * 10, MAX_CRIT_CHANCE),
+            life_steal: 0,
+            regeneration: 0,
+            resistance: 0,
+            level,
+        };
+
+        // Battle style shifts the attack/defense balance
+        match battle_style {
+            BattleStyle::Aggressive => {
+                combatant.attack = combatant.attack * 120 / 100;
+                combatant.defense = combatant.defense * 90 / 100;
+            },
+            BattleStyle::Defensive => {
+                combatant.attack = combatant.attack * 90 / 100;
+                combatant.defense = combatant.defense * 125 / 100;
+            },
+            BattleStyle::Balanced => {
+                combatant.attack = combatant.attack * 105 / 100;
+                combatant.defense = combatant.defense * 105 / 100;
+            },
+            BattleStyle::Tactical => {
+                combatant.attack = combatant.attack * 110 / 100;
+                combatant.crit_chance = core::cmp::min(combatant.crit_chance + 500, MAX_CRIT_CHANCE);
+            },
+            BattleStyle::Berserker => {
+                combatant.attack = combatant.attack * 135 / 100;
+                combatant.defense = combatant.defense * 75 / 100;
+                combatant.life_steal += 5;
+            },
+        }
+
+        combatant.max_health = combatant.health;
+        combatant
+    }
+
+    pub fn apply_effect(&mut self, effect: &SpecialEffect) {
+        let value = effect.value as u64;
+        match effect.effect_type {
+            EffectType::DamageBoost => self.attack += self.attack * value / 100,
+            EffectType::DefenseBoost => self.defense += self.defense * value / 100,
+            EffectType::SpeedBoost => self.speed += self.speed * value / 100,
+            EffectType::CriticalChance => {
+                self.crit_chance = core::cmp::min(self.crit_chance + value * 100, MAX_CRIT_CHANCE);
+            },
+            EffectType::LifeSteal => self.life_steal = core::cmp::min(self.life_steal + value, 100),
+            EffectType::ManaRegeneration => self.regeneration += value,
+            EffectType::ElementalResistance => {
+                self.resistance = core::cmp::min(self.resistance + value, MAX_DAMAGE_RESISTANCE);
+            },
+            EffectType::AILearningBoost => {}, // No combat effect, only affects experience
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        self.health > 0
+    }
+}
+
+pub fn add_stats(base: &mut HeroStats, bonus: &HeroStats) {
+    base.strength += bonus.strength;
+    base.intelligence += bonus.intelligence;
+    base.agility += bonus.agility;
+    base.vitality += bonus.vitality;
+    base.luck += bonus.luck;
+    base.magic_power += bonus.magic_power;
+}
+
+// Percentage buffs on the underlying stats; the remaining effects apply to the combatant
+pub fn is_stat_buff(effect_type: &EffectType) -> bool {
+    matches!(
+        effect_type,
+        EffectType::DamageBoost | EffectType::DefenseBoost | EffectType::SpeedBoost | EffectType::CriticalChance
+    )
+}
+
+pub fn apply_stat_buff(stats: &mut HeroStats, effect: &SpecialEffect) {
+    let value = effect.value;
+    match effect.effect_type {
+        EffectType::DamageBoost => {
+            stats.strength += stats.strength * value / 100;
+            stats.magic_power += stats.magic_power * value / 100;
+        },
+        EffectType::DefenseBoost => stats.vitality += stats.vitality * value / 100,
+        EffectType::SpeedBoost => stats.agility += stats.agility * value / 100,
+        EffectType::CriticalChance => stats.luck += stats.luck * value / 100,
+        _ => {},
+    }
+}
+
+// Small xorshift generator so a battle replays identically from the same seed
+struct CombatRng {
+    state: u64,
+}
+
+impl CombatRng {
+    fn new(seed: u64) -> Self {
+        CombatRng { state: (seed ^ COMBAT_SEED_SALT) | 1 }
+    }
+
+    fn next(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    fn next_in_range(&mut self, min: u64, max: u64) -> u64 {
+        min + self.next() % (max - min + 1)
+    }
+}
+
+// Turn-based free-for-all: each round every living combatant, fastest first,
+// strikes the weakest living opponent until a single combatant is left standing.
+pub fn resolve_combat(combatants: &mut [Combatant], seed: u64) -> CombatOutcome {
+    let count = combatants.len();
+    let mut rng = CombatRng::new(seed);
+    let mut outcome = CombatOutcome {
+        winner: 0,
+        rounds: 0,
+        damage_dealt: [0u64; MAX_BATTLE_PARTICIPANTS],
+        damage_taken: [0u64; MAX_BATTLE_PARTICIPANTS],
+    };
+
+    let mut turn_order = [0usize; MAX_BATTLE_PARTICIPANTS];
+    for (index, slot) in turn_order.iter_mut().enumerate().take(count) {
+        *slot = index;
+    }
+    turn_order[..count].sort_unstable_by(|a, b| combatants[*b].speed.cmp(&combatants[*a].speed).then(a.cmp(b)));
+
+    while outcome.rounds < BATTLE_MAX_ROUNDS && alive_count(combatants) > 1 {
+        outcome.rounds += 1;
+
+        for &attacker in turn_order[..count].iter() {
+            if !combatants[attacker].is_alive() {
+                continue;
+            }
+            let target = match weakest_opponent(combatants, attacker) {
+                Some(target) => target,
+                None => break,
+            };
+
+            let damage = roll_damage(&combatants[attacker], &combatants[target], &mut rng);
+            let dealt = core::cmp::min(damage, combatants[target].health);
+            combatants[target].health -= dealt;
+            outcome.damage_dealt[attacker] += dealt;
+            outcome.damage_taken[target] += dealt;
+
+            let healed = dealt * combatants[attacker].life_steal / 100;
+            heal(&mut combatants[attacker], healed);
+        }
+
+        for combatant in combatants.iter_mut() {
+            if combatant.is_alive() {
+                let regeneration = combatant.regeneration;
+                heal(combatant, regeneration);
+            }
+        }
+    }
+
+    outcome.winner = strongest_survivor(combatants);
+    outcome
+}
+
+pub fn battle_experience(won: bool, rounds: u32, opponent_levels: u64) -> u64 {
+    let experience = BATTLE_BASE_EXPERIENCE
+        + rounds as u64 * BATTLE_EXPERIENCE_PER_ROUND
+        + opponent_levels * BATTLE_EXPERIENCE_PER_OPPONENT_LEVEL;
+
+    if won {
+        experience
+    } else {
+        experience * BATTLE_LOSER_EXPERIENCE_PERCENTAGE / 100
+    }
+}
+
+fn roll_damage(attacker: &Combatant, defender: &Combatant, rng: &mut CombatRng) -> u64 {
+    let attack = attacker.attack;
+    let mut damage = attack * attack / core::cmp::max(attack + defender.defense, 1);
+
+    // +/-10% variance keeps mirror matches from always favouring the first mover
+    damage = damage * rng.next_in_range(90, 110) / 100;
+
+    if rng.next_in_range(1, 10_000) <= attacker.crit_chance {
+        damage = damage * 150 / 100;
+    }
+
+    damage -= damage * defender.resistance / 100;
+    core::cmp::max(damage, 1)
+}
+
+fn heal(combatant: &mut Combatant, amount: u64) {
+    combatant.health = core::cmp::min(combatant.health + amount, combatant.max_health);
+}
+
+fn alive_count(combatants: &[Combatant]) -> usize {
+    combatants.iter().filter(|combatant| combatant.is_alive()).count()
+}
+
+fn weakest_opponent(combatants: &[Combatant], attacker: usize) -> Option<usize> {
+    let mut weakest: Option<usize> = None;
+    for (index, combatant) in combatants.iter().enumerate() {
+        if index == attacker || !combatant.is_alive() {
+            continue;
+        }
+        match weakest {
+            Some(current) if combatants[current].health <= combatant.health => {},
+            _ => weakest = Some(index),
+        }
+    }
+    weakest
+}
+
+// Highest remaining health ratio wins; only matters when the round cap is hit
+fn strongest_survivor(combatants: &[Combatant]) -> usize {
+    let mut best = 0usize;
+    let mut best_ratio = 0u64;
+    for (index, combatant) in combatants.iter().enumerate() {
+        let ratio = combatant.health * 10_000 / core::cmp::max(combatant.max_health, 1);
+        if ratio > best_ratio {
+            best = index;
+            best_ratio = ratio;
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn combatant(health: u64, attack: u64, defense: u64, speed: u64) -> Combatant {
+        Combatant {
+            health,
+            max_health: health,
+            attack,
+            defense,
+            speed,
+            level: 1,
+            ..Default::default()
+        }
+    }
+
+    // Takes at most 11 damage a round from a 10 attack opponent and heals it all back
+    fn unkillable() -> Combatant {
+        Combatant {
+            regeneration: 100,
+            ..combatant(1_000, 10, 0, 10)
+        }
+    }
+
+    #[test]
+    fn same_seed_replays_the_same_battle() {
+        let fighters = [
+            combatant(500, 60, 20, 10),
+            combatant(480, 55, 25, 12),
+            combatant(520, 50, 30, 8),
+        ];
+        let mut first = fighters;
+        let mut second = fighters;
+
+        let first_outcome = resolve_combat(&mut first, 42);
+        let second_outcome = resolve_combat(&mut second, 42);
+
+        assert_eq!(first_outcome, second_outcome);
+        assert_eq!(first, second);
+        assert!(first_outcome.rounds > 0);
+    }
+
+    #[test]
+    fn battle_ends_when_one_combatant_is_left() {
+        let mut fighters = [combatant(10, 1, 0, 5), combatant(500, 1_000, 0, 20)];
+
+        let outcome = resolve_combat(&mut fighters, 7);
+
+        assert_eq!(outcome.winner, 1);
+        assert_eq!(outcome.rounds, 1);
+        assert_eq!(fighters[0].health, 0);
+        assert_eq!(outcome.damage_taken[0], 10);
+        assert_eq!(outcome.damage_dealt[1], 10);
+    }
+
+    #[test]
+    fn faster_combatant_strikes_first() {
+        let mut fighters = [combatant(10, 1_000, 0, 10), combatant(10, 1_000, 0, 20)];
+
+        let outcome = resolve_combat(&mut fighters, 7);
+
+        assert_eq!(outcome.winner, 1);
+        assert_eq!(outcome.damage_taken[1], 0);
+    }
+
+    #[test]
+    fn battle_stops_at_round_cap() {
+        let mut fighters = [unkillable(), unkillable()];
+
+        let outcome = resolve_combat(&mut fighters, 99);
+
+        assert_eq!(outcome.rounds, BATTLE_MAX_ROUNDS);
+        assert!(fighters.iter().all(|fighter| fighter.health > 0));
+    }
+
+    #[test]
+    fn round_cap_goes_to_highest_health_ratio() {
+        let mut fighters = [Combatant { regeneration: 0, ..unkillable() }, unkillable()];
+
+        let outcome = resolve_combat(&mut fighters, 99);
+
+        assert_eq!(outcome.rounds, BATTLE_MAX_ROUNDS);
+        assert_eq!(outcome.winner, 1);
+    }
+
+    #[test]
+    fn tie_at_round_cap_goes_to_first_combatant() {
+        let mut fighters = [unkillable(), unkillable(), unkillable()];
+
+        let outcome = resolve_combat(&mut fighters, 3);
+
+        assert_eq!(fighters[0].health, fighters[0].max_health);
+        assert_eq!(fighters[1].health, fighters[1].max_health);
+        assert_eq!(outcome.winner, 0);
+    }
+
+    #[test]
+    fn every_hit_deals_at_least_one_damage() {
+        let mut fighters = [combatant(5, 1, 10_000, 10), combatant(5, 1, 10_000, 5)];
+
+        let outcome = resolve_combat(&mut fighters, 1);
+
+        assert_eq!(outcome.rounds, 5);
+        assert_eq!(outcome.winner, 0);
+    }
+
+    #[test]
+    fn loser_earns_a_share_of_the_experience() {
+        let won = battle_experience(true, 4, 3);
+        assert_eq!(
+            won,
+            BATTLE_BASE_EXPERIENCE + 4 * BATTLE_EXPERIENCE_PER_ROUND + 3 * BATTLE_EXPERIENCE_PER_OPPONENT_LEVEL
+        );
+        assert_eq!(battle_experience(false, 4, 3), won * BATTLE_LOSER_EXPERIENCE_PERCENTAGE / 100);
+    }
+
+    #[test]
+    fn stat_buffs_scale_the_underlying_stats() {
+        let mut stats = HeroStats {
+            strength: 100,
+            intelligence: 80,
+            agility: 60,
+            vitality: 90,
+            luck: 50,
+            magic_power: 40,
+        };
+        let damage_boost = SpecialEffect {
+            effect_type: EffectType::DamageBoost,
+            value: 50,
+            duration: 3,
+        };
+        let life_steal = SpecialEffect {
+            effect_type: EffectType::LifeSteal,
+            value: 50,
+            duration: 3,
+        };
+
+        apply_stat_buff(&mut stats, &damage_boost);
+        assert_eq!(stats.strength, 150);
+        assert_eq!(stats.magic_power, 60);
+        assert_eq!(stats.intelligence, 80);
+
+        let before = stats.clone();
+        assert!(!is_stat_buff(&life_steal.effect_type));
+        apply_stat_buff(&mut stats, &life_steal);
+        assert_eq!(stats, before);
+    }
+}
@@ -4,18 +4,64 @@ pub const MAX_ITEM_LEVEL: u32 = 50;
 pub const MAX_EVOLUTION_STAGE: u32 = 10;
 pub const BASE_STAT_POINTS: u32 = 100;
 pub const STAT_POINTS_PER_LEVEL: u32 = 5;
+pub const ROLL_REVEAL_WINDOW: u64 = 86400; // 24 hours to reveal a committed roll
+pub const ELEMENTAL_ABILITIES: [u32; 5] = [100, 101, 102, 103, 104]; // Fire, Water, Earth, Air, Lightning
 
 // AI Constants
 pub const AI_EVOLUTION_THRESHOLD: u64 = 1000; // usage count for AI evolution
 pub const AI_LEARNING_COOLDOWN: u64 = 86400; // 24 hours in seconds
 pub const MAX_AI_REQUESTS_PER_HOUR: u32 = 100;
+pub const AI_COMPANION_REQUESTS_PER_HOUR: u32 = 500;
+pub const AI_COMPANION_LEARNING_COOLDOWN: u64 = 21600; // 6 hours in seconds
+pub const AI_REQUEST_WINDOW: u64 = 3600; // 1 hour
+pub const AI_COMPANION_PLAN: &[u8] = b"ai_companion";
+pub const PREMIUM_PLAN: &[u8] = b"premium";
 pub const AI_PREDICTION_CONFIDENCE_THRESHOLD: u32 = 75;
+pub const AI_HERO_GENERATION_TIMEOUT: u64 = 3600; // 1 hour
+pub const AI_REQUEST_DEFAULT_TIMEOUT: u64 = 1800; // 30 minutes
+pub const MAX_AI_REQUEST_RETRIES: u32 = 3;
+pub const AI_HERO_GENERATION_FEE: u64 = 1_000_000_000_000_000_000; // 1 EGLD
+pub const BASIC_HERO_GENERATION_FEE: u64 = 500_000_000_000_000_000; // 0.5 EGLD
+pub const MAX_AI_RESPONSE_LENGTH: usize = 512;
+pub const MIN_AI_STAT_VALUE: u32 = 50;
+pub const MAX_AI_STAT_VALUE: u32 = 200;
+pub const MAX_AI_STAT_TOTAL: u32 = 720; // base points plus a 120 point AI budget
+pub const MAX_AI_TRAIT_VALUE: u32 = 100;
+
+// Item Constants
+pub const ITEM_CRAFTING_FEE: u64 = 200_000_000_000_000_000; // 0.2 EGLD
+pub const BASE_ITEM_DURABILITY: u32 = 100;
+pub const ITEM_DURABILITY_PER_TIER: u32 = 50;
+pub const ITEM_WEAR_PER_BATTLE: u32 = 6; // divided by the rarity tier, at least 1
+pub const ITEM_EVOLUTION_USES_PER_STAGE: u64 = 20; // default threshold step when none are configured
+pub const ITEM_EVOLUTION_BONUS_PERCENTAGE: u32 = 10;
+pub const ITEM_EFFECT_STAGE_INTERVAL: u32 = 5; // a special effect every 5 stages
+pub const CONSUMABLE_DEFAULT_DURATION: u64 = 3600; // used when an effect has no duration
+pub const CONSUMABLE_MAX_BATTLES: u32 = 5;
+pub const MAX_ACTIVE_BUFFS: usize = 10;
+pub const MAX_RECIPE_INPUTS: usize = 5;
+pub const ITEM_FUSION_INPUTS: usize = 3;
+pub const ITEM_FUSION_FEE: u64 = 300_000_000_000_000_000; // 0.3 EGLD
+pub const ITEM_FUSION_SUCCESS_CHANCE: u64 = 8000; // 80% (basis points)
+pub const ITEM_REPAIR_COST_PER_POINT: u64 = 1_000_000_000_000_000; // 0.001 EGLD, times the rarity tier
 
 // Battle Constants
 pub const BATTLE_TIMEOUT: u64 = 1800; // 30 minutes
 pub const MAX_BATTLE_PARTICIPANTS: usize = 10;
 pub const TOURNAMENT_ENTRY_FEE: u64 = 1_000_000_000_000_000_000; // 1 EGLD
 pub const PVP_MATCH_FEE: u64 = 100_000_000_000_000_000; // 0.1 EGLD
+pub const BATTLE_MAX_ROUNDS: u32 = 50;
+pub const BATTLE_BASE_EXPERIENCE: u64 = 50;
+pub const BATTLE_EXPERIENCE_PER_ROUND: u64 = 5;
+pub const BATTLE_EXPERIENCE_PER_OPPONENT_LEVEL: u64 = 10;
+pub const BATTLE_LOSER_EXPERIENCE_PERCENTAGE: u64 = 30;
+pub const MAX_CRIT_CHANCE: u64 = 5000; // 50% (basis points)
+pub const MAX_DAMAGE_RESISTANCE: u64 = 75; // percentage
+pub const MIN_TOURNAMENT_SIZE: u32 = 4;
+pub const MAX_TOURNAMENT_SIZE: u32 = 32;
+pub const PVP_POWER_TOLERANCE_PERCENTAGE: u64 = 20;
+pub const PVP_QUEUE_BAND_CAPACITY: usize = 20;
+pub const COMBAT_SEED_SALT: u64 = 0x9E37_79B9_7F4A_7C15;
 
 // Cross-chain Constants
 pub const BRIDGE_FEE_PERCENTAGE: u64 = 50; // 0.5% (basis points)
@@ -24,9 +70,21 @@ pub const CROSS_CHAIN_TIMEOUT: u64 = 3600; // 1 hour
 
 // Revenue Constants
 pub const AI_COMPANION_MONTHLY_FEE: u64 = 15_000_000_000_000_000_000; // 15 EGLD
+pub const PREMIUM_MONTHLY_FEE: u64 = 5_000_000_000_000_000_000; // 5 EGLD
 pub const NFT_EVOLUTION_FEE: u64 = 8_000_000_000_000_000_000; // 8 EGLD
+pub const SUBSCRIBER_EVOLUTION_DISCOUNT: u64 = 2_500; // 25% (basis points)
+pub const MIN_AUCTION_DURATION: u64 = 3600; // 1 hour
+pub const MAX_AUCTION_DURATION: u64 = 2592000; // 30 days
+pub const AUCTION_EXTENSION_WINDOW: u64 = 600; // bids in the last 10 minutes push the end back
+pub const MAX_OFFER_DURATION: u64 = 2592000; // 30 days
+pub const MIN_RENTAL_DURATION: u64 = 86400; // 1 day
+pub const MAX_RENTAL_DURATION: u64 = 2592000; // 30 days
+pub const HERO_NFT_DEFAULT_ROYALTIES: u64 = 500; // 5% (basis points)
+pub const CREATOR_ROYALTY_DEFAULT: u64 = 250; // 2.5% (basis points)
+pub const MAX_CREATOR_ROYALTY: u64 = 1_000; // 10% (basis points)
 pub const MARKETPLACE_FEE_PERCENTAGE: u64 = 250; // 2.5% (basis points)
 pub const TOURNAMENT_FEE_PERCENTAGE: u64 = 100; // 1% (basis points)
+pub const BASIS_POINTS_DIVISOR: u64 = 10_000;
 
 // Time Constants
 pub const SECONDS_PER_DAY: u64 = 86400;
@@ -38,6 +96,7 @@ pub const MAX_HEROES_PER_ACCOUNT: u32 = 50;
 pub const MAX_ITEMS_PER_ACCOUNT: u32 = 200;
 pub const MAX_NAME_LENGTH: usize = 32;
 pub const MAX_DESCRIPTION_LENGTH: usize = 256;
+pub const MAX_LISTINGS_PAGE_SIZE: usize = 50;
 
 // Gas Limits
 pub const AI_REQUEST_GAS_LIMIT: u64 = 10_000_000;
@@ -6,6 +6,7 @@ pub const ERROR_INVALID_PAYMENT: &str = "Invalid payment amount";
 pub const ERROR_INSUFFICIENT_FUNDS: &str = "Insufficient funds";
 pub const ERROR_CONTRACT_PAUSED: &str = "Contract is paused";
 pub const ERROR_INVALID_ADDRESS: &str = "Invalid address provided";
+pub const ERROR_CONTRACT_CALLER_NOT_ALLOWED: &str = "Smart contract callers are not allowed";
 
 // Hero Errors
 pub const ERROR_HERO_NOT_FOUND: &str = "Hero not found";
@@ -15,6 +16,22 @@ pub const ERROR_INSUFFICIENT_EXPERIENCE: &str = "Insufficient experience for evo
 pub const ERROR_HERO_IN_BATTLE: &str = "Hero is currently in battle";
 pub const ERROR_MAX_HEROES_REACHED: &str = "Maximum heroes per account reached";
 pub const ERROR_INVALID_HERO_CLASS: &str = "Invalid hero class";
+pub const ERROR_HERO_GENERATION_PENDING: &str = "Hero is still waiting for AI generation";
+pub const ERROR_COMMIT_REVEAL_REQUIRED: &str = "This roll must use commitRoll and revealRoll";
+pub const ERROR_INVALID_ROLL_PURPOSE: &str = "This evolution has no random outcome to commit";
+pub const ERROR_ROLL_COMMIT_PENDING: &str = "Hero has an unrevealed roll commitment";
+pub const ERROR_ROLL_COMMIT_NOT_FOUND: &str = "No roll commitment for this hero";
+pub const ERROR_NOT_ROLL_COMMITTER: &str = "Only the committer can reveal this roll";
+pub const ERROR_ROLL_REVEAL_TOO_EARLY: &str = "Roll must be revealed in a later block";
+pub const ERROR_ROLL_COMMIT_EXPIRED: &str = "Roll reveal window has passed";
+pub const ERROR_ROLL_COMMIT_NOT_EXPIRED: &str = "Roll reveal window is still open";
+pub const ERROR_INVALID_ROLL_REVEAL: &str = "Revealed secret does not match the commitment";
+pub const ERROR_NO_ABILITY_TO_ROLL: &str = "Hero already has every rollable ability";
+pub const ERROR_ABILITY_REQUIRES_ROLL: &str = "Elemental abilities can only be unlocked through commitRoll";
+pub const ERROR_HERO_COLLECTION_NOT_ISSUED: &str = "Hero NFT collection has not been issued";
+pub const ERROR_HERO_WITHDRAWN: &str = "Hero NFT is held outside the contract";
+pub const ERROR_INVALID_HERO_NFT: &str = "Payment is not a hero NFT of this collection";
+pub const ERROR_INVALID_ROYALTIES: &str = "Invalid royalties";
 
 // Equipment Errors
 pub const ERROR_ITEM_NOT_FOUND: &str = "Item not found";
@@ -24,6 +41,27 @@ pub const ERROR_INCOMPATIBLE_ITEM: &str = "Item incompatible with hero";
 pub const ERROR_ITEM_BROKEN: &str = "Item is broken and cannot be used";
 pub const ERROR_MAX_ITEMS_REACHED: &str = "Maximum items per account reached";
 pub const ERROR_INVALID_ITEM_TYPE: &str = "Invalid item type";
+pub const ERROR_ITEM_MAX_STAGE: &str = "Item has reached its final evolution stage";
+pub const ERROR_INVALID_EVOLUTION_THRESHOLDS: &str = "Evolution thresholds must be strictly increasing, one per stage";
+pub const ERROR_INVALID_EVOLUTION_STAGE: &str = "Evolution stage out of range";
+pub const ERROR_CONSUMABLE_HAS_NO_EFFECTS: &str = "Consumable has no effects";
+pub const ERROR_MAX_BUFFS_REACHED: &str = "Hero has too many active buffs";
+pub const ERROR_RECIPE_NOT_FOUND: &str = "Crafting recipe not found";
+pub const ERROR_RECIPE_INACTIVE: &str = "Crafting recipe is not active";
+pub const ERROR_INVALID_RECIPE: &str = "Invalid crafting recipe";
+pub const ERROR_RECIPE_INPUT_MISMATCH: &str = "Items do not match the recipe inputs";
+pub const ERROR_DUPLICATE_CRAFTING_INPUT: &str = "The same item was supplied twice";
+pub const ERROR_FUSION_INPUT_MISMATCH: &str = "Fused items must share item type and rarity";
+pub const ERROR_CANNOT_FUSE_RARITY: &str = "Items of this rarity cannot be fused";
+pub const ERROR_ITEM_NOT_DAMAGED: &str = "Item is already at full durability";
+pub const ERROR_ITEM_EQUIPPED: &str = "Item must be unequipped first";
+pub const ERROR_EQUIPMENT_SLOT_EMPTY: &str = "Equipment slot is empty";
+pub const ERROR_INVALID_ITEM_NAME: &str = "Invalid item name";
+pub const ERROR_INVALID_ITEM_LEVEL: &str = "Invalid item level";
+pub const ERROR_ITEM_COLLECTION_NOT_ISSUED: &str = "Item token collection has not been issued";
+pub const ERROR_ITEM_NOT_DEPOSITED: &str = "Item token is held outside the contract";
+pub const ERROR_INVALID_ITEM_TOKEN: &str = "Payment is not an item token of this contract";
+pub const ERROR_NOT_ITEM_MINTER: &str = "Only owner or AI oracle can mint items";
 
 // Battle Errors
 pub const ERROR_BATTLE_NOT_FOUND: &str = "Battle not found";
@@ -34,6 +72,27 @@ pub const ERROR_NOT_BATTLE_PARTICIPANT: &str = "Not a battle participant";
 pub const ERROR_HERO_ALREADY_IN_BATTLE: &str = "Hero is already in another battle";
 pub const ERROR_INSUFFICIENT_PARTICIPANTS: &str = "Insufficient battle participants";
 pub const ERROR_BATTLE_TIMEOUT: &str = "Battle has timed out";
+pub const ERROR_BATTLE_FULL: &str = "Battle has reached maximum participants";
+pub const ERROR_ALREADY_BATTLE_PARTICIPANT: &str = "Already participating in this battle";
+pub const ERROR_NOT_BATTLE_CREATOR: &str = "Only the battle creator can perform this action";
+pub const ERROR_BATTLE_NOT_TIMED_OUT: &str = "Battle has not timed out yet";
+pub const ERROR_INVALID_BATTLE_TYPE: &str = "Invalid battle type for this action";
+pub const ERROR_HERO_NOT_IN_QUEUE: &str = "Hero is not in the matchmaking queue";
+pub const ERROR_NOT_QUEUE_ENTRY_OWNER: &str = "Only the queued player can leave the queue";
+pub const ERROR_PVP_QUEUE_BAND_FULL: &str = "Matchmaking queue is full for this power range";
+
+// Tournament Errors
+pub const ERROR_TOURNAMENT_NOT_FOUND: &str = "Tournament not found";
+pub const ERROR_INVALID_TOURNAMENT_SIZE: &str = "Tournament size must be a power of two within limits";
+pub const ERROR_INVALID_PRIZE_SPLIT: &str = "Prize split must cover the full pool";
+pub const ERROR_INVALID_START_TIME: &str = "Start time must be in the future";
+pub const ERROR_TOURNAMENT_REGISTRATION_CLOSED: &str = "Tournament registration is closed";
+pub const ERROR_TOURNAMENT_FULL: &str = "Tournament is full";
+pub const ERROR_TOURNAMENT_NOT_FULL: &str = "Tournament does not have enough participants";
+pub const ERROR_TOURNAMENT_NOT_STARTED: &str = "Tournament start time not reached";
+pub const ERROR_TOURNAMENT_NOT_IN_PROGRESS: &str = "Tournament is not in progress";
+pub const ERROR_TOURNAMENT_ROUND_IN_PROGRESS: &str = "Current tournament round is still in progress";
+pub const ERROR_TOURNAMENT_CANNOT_CANCEL: &str = "Tournament cannot be cancelled";
 
 // AI Errors
 pub const ERROR_AI_REQUEST_FAILED: &str = "AI request failed";
@@ -42,6 +101,13 @@ pub const ERROR_AI_COOLDOWN_ACTIVE: &str = "AI cooldown period active";
 pub const ERROR_AI_QUOTA_EXCEEDED: &str = "AI request quota exceeded";
 pub const ERROR_AI_RESPONSE_INVALID: &str = "Invalid AI response format";
 pub const ERROR_AI_SERVICE_UNAVAILABLE: &str = "AI service temporarily unavailable";
+pub const ERROR_NOT_AI_ORACLE: &str = "Caller is not an authorized AI oracle";
+pub const ERROR_AI_REQUEST_NOT_FOUND: &str = "AI request not found";
+pub const ERROR_AI_REQUEST_ALREADY_PROCESSED: &str = "AI request already processed";
+pub const ERROR_AI_REQUEST_EXPIRED: &str = "AI request deadline has passed";
+pub const ERROR_AI_REQUEST_NOT_EXPIRED: &str = "AI request deadline has not passed yet";
+pub const ERROR_AI_RETRY_LIMIT_REACHED: &str = "AI request retry limit reached";
+pub const ERROR_INVALID_AI_DEADLINE: &str = "Invalid AI request deadline";
 
 // Cross-chain Errors
 pub const ERROR_INVALID_CHAIN_ID: &str = "Invalid destination chain ID";
@@ -63,3 +129,32 @@ pub const ERROR_INVALID_PRICE: &str = "Invalid sale price";
 pub const ERROR_CANNOT_BUY_OWN_ASSET: &str = "Cannot buy your own asset";
 pub const ERROR_SALE_EXPIRED: &str = "Sale has expired";
 pub const ERROR_ASSET_NOT_OWNED_BY_SELLER: &str = "Asset not owned by seller";
+pub const ERROR_ASSET_LISTED: &str = "Asset is listed on the marketplace";
+pub const ERROR_LISTING_NOT_FOUND: &str = "Listing not found";
+pub const ERROR_NOT_LISTING_SELLER: &str = "Only the seller can change this listing";
+pub const ERROR_INVALID_LISTING_EXPIRY: &str = "Listing expiry must be in the future";
+pub const ERROR_AUCTION_NOT_FOUND: &str = "Auction not found";
+pub const ERROR_AUCTION_ENDED: &str = "Auction has ended";
+pub const ERROR_AUCTION_NOT_ENDED: &str = "Auction has not ended yet";
+pub const ERROR_AUCTION_HAS_BIDS: &str = "Auction already has bids";
+pub const ERROR_NOT_AUCTION_SELLER: &str = "Only the seller can cancel this auction";
+pub const ERROR_BID_TOO_LOW: &str = "Bid is below the minimum accepted amount";
+pub const ERROR_INVALID_AUCTION_DURATION: &str = "Invalid auction duration";
+pub const ERROR_INVALID_RESERVE_PRICE: &str = "Invalid auction reserve price";
+pub const ERROR_INVALID_BID_INCREMENT: &str = "Minimum bid increment must be positive";
+pub const ERROR_NO_REFUND_TO_CLAIM: &str = "No auction refund to claim";
+pub const ERROR_OFFER_NOT_FOUND: &str = "Offer not found";
+pub const ERROR_OFFER_EXPIRED: &str = "Offer has expired";
+pub const ERROR_NOT_OFFER_BUYER: &str = "Only the buyer can cancel this offer";
+pub const ERROR_INVALID_OFFER_EXPIRY: &str = "Invalid offer expiry";
+pub const ERROR_OFFER_TARGET_MISMATCH: &str = "Hero does not match the offer";
+pub const ERROR_NO_ROYALTIES_TO_CLAIM: &str = "No royalties to claim";
+
+// Rental Errors
+pub const ERROR_HERO_RENTED: &str = "Hero is rented out";
+pub const ERROR_NOT_HERO_USER: &str = "Hero is not usable by caller";
+pub const ERROR_RENTAL_LISTING_NOT_FOUND: &str = "Hero is not listed for rent";
+pub const ERROR_RENTAL_NOT_FOUND: &str = "Hero has no rental";
+pub const ERROR_RENTAL_NOT_EXPIRED: &str = "Rental has not expired yet";
+pub const ERROR_INVALID_RENTAL_DURATION: &str = "Invalid rental duration";
+pub const ERROR_INVALID_REVENUE_SHARE: &str = "Invalid revenue share";
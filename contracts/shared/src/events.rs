@@ -15,6 +15,14 @@ pub trait EventsModule {
         ai_generated: bool,
     );
 
+    #[event("ai_hero_finalized")]
+    fn ai_hero_finalized_event(
+        &self,
+        #[indexed] hero_id: u64,
+        #[indexed] request_id: u64,
+        ai_result_applied: bool,
+    );
+
     #[event("hero_evolved")]
     fn hero_evolved_event(
         &self,
@@ -25,6 +33,22 @@ pub trait EventsModule {
         ai_evolution: bool,
     );
 
+    #[event("hero_withdrawn")]
+    fn hero_withdrawn_event(
+        &self,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] hero_id: u64,
+        nft_nonce: u64,
+    );
+
+    #[event("hero_deposited")]
+    fn hero_deposited_event(
+        &self,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] hero_id: u64,
+        nft_nonce: u64,
+    );
+
     #[event("hero_stats_updated")]
     fn hero_stats_updated_event(
         &self,
@@ -33,6 +57,31 @@ pub trait EventsModule {
         new_stats: &HeroStats,
     );
 
+    #[event("roll_committed")]
+    fn roll_committed_event(
+        &self,
+        #[indexed] hero_id: u64,
+        #[indexed] owner: &ManagedAddress,
+        purpose: &RollPurpose,
+        deadline: u64,
+    );
+
+    #[event("roll_revealed")]
+    fn roll_revealed_event(
+        &self,
+        #[indexed] hero_id: u64,
+        purpose: &RollPurpose,
+        seed: u64,
+    );
+
+    #[event("roll_forfeited")]
+    fn roll_forfeited_event(
+        &self,
+        #[indexed] hero_id: u64,
+        #[indexed] owner: &ManagedAddress,
+        amount: &BigUint,
+    );
+
     // Equipment Events
     #[event("item_created")]
     fn item_created_event(
@@ -44,6 +93,56 @@ pub trait EventsModule {
         ai_generated: bool,
     );
 
+    #[event("item_transferred")]
+    fn item_transferred_event(
+        &self,
+        #[indexed] item_id: u64,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] to: &ManagedAddress,
+    );
+
+    #[event("consumable_used")]
+    fn consumable_used_event(
+        &self,
+        #[indexed] hero_id: u64,
+        #[indexed] item_id: u64,
+        effect_count: u32,
+    );
+
+    #[event("recipe_crafted")]
+    fn recipe_crafted_event(
+        &self,
+        #[indexed] crafter: &ManagedAddress,
+        #[indexed] recipe_id: u64,
+        success: bool,
+        output_item_id: u64,
+    );
+
+    #[event("items_fused")]
+    fn items_fused_event(
+        &self,
+        #[indexed] owner: &ManagedAddress,
+        input_rarity: &Rarity,
+        success: bool,
+        output_item_id: u64,
+    );
+
+    #[event("item_broken")]
+    fn item_broken_event(
+        &self,
+        #[indexed] item_id: u64,
+        #[indexed] owner: &ManagedAddress,
+    );
+
+    #[event("item_repaired")]
+    fn item_repaired_event(
+        &self,
+        #[indexed] item_id: u64,
+        #[indexed] owner: &ManagedAddress,
+        restored_durability: u32,
+        cost: &BigUint,
+    );
+
     #[event("item_evolved")]
     fn item_evolved_event(
         &self,
@@ -53,6 +152,22 @@ pub trait EventsModule {
         usage_based: bool,
     );
 
+    #[event("item_withdrawn")]
+    fn item_withdrawn_event(
+        &self,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] item_id: u64,
+        token_nonce: u64,
+    );
+
+    #[event("item_deposited")]
+    fn item_deposited_event(
+        &self,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] item_id: u64,
+        token_nonce: u64,
+    );
+
     #[event("item_equipped")]
     fn item_equipped_event(
         &self,
@@ -61,6 +176,14 @@ pub trait EventsModule {
         slot: &ItemType,
     );
 
+    #[event("item_unequipped")]
+    fn item_unequipped_event(
+        &self,
+        #[indexed] hero_id: u64,
+        #[indexed] item_id: u64,
+        slot: &ItemType,
+    );
+
     // Battle Events
     #[event("battle_started")]
     fn battle_started_event(
@@ -88,6 +211,55 @@ pub trait EventsModule {
         confidence: u32,
     );
 
+    #[event("pvp_queue_joined")]
+    fn pvp_queue_joined_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] hero_id: u64,
+        power: u64,
+    );
+
+    #[event("pvp_queue_left")]
+    fn pvp_queue_left_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] hero_id: u64,
+        refund: &BigUint,
+    );
+
+    // Tournament Events
+    #[event("tournament_created")]
+    fn tournament_created_event(
+        &self,
+        #[indexed] tournament_id: u64,
+        size: u32,
+        start_time: u64,
+        entry_fee: &BigUint,
+    );
+
+    #[event("tournament_round_started")]
+    fn tournament_round_started_event(
+        &self,
+        #[indexed] tournament_id: u64,
+        #[indexed] round: u32,
+        battle_count: u32,
+    );
+
+    #[event("tournament_completed")]
+    fn tournament_completed_event(
+        &self,
+        #[indexed] tournament_id: u64,
+        #[indexed] champion_hero_id: u64,
+        prize_pool: &BigUint,
+    );
+
+    #[event("tournament_cancelled")]
+    fn tournament_cancelled_event(
+        &self,
+        #[indexed] tournament_id: u64,
+        refunded_entries: u32,
+    );
+
     // AI Integration Events
     #[event("ai_request_submitted")]
     fn ai_request_submitted_event(
@@ -105,6 +277,195 @@ pub trait EventsModule {
         gas_used: u64,
     );
 
+    #[event("ai_request_retried")]
+    fn ai_request_retried_event(
+        &self,
+        #[indexed] request_id: u64,
+        retry_count: u32,
+        new_deadline: u64,
+    );
+
+    #[event("ai_request_expired")]
+    fn ai_request_expired_event(
+        &self,
+        #[indexed] request_id: u64,
+        request_type: &AIRequestType,
+        #[indexed] requester: &ManagedAddress,
+    );
+
+    #[event("ai_request_refunded")]
+    fn ai_request_refunded_event(
+        &self,
+        #[indexed] request_id: u64,
+        #[indexed] requester: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    // Marketplace Events
+    #[event("asset_listed")]
+    fn asset_listed_event(
+        &self,
+        #[indexed] listing_id: u64,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] asset_type: &AssetType,
+        #[indexed] asset_id: u64,
+        price: &BigUint,
+        expires_at: u64,
+    );
+
+    #[event("listing_price_updated")]
+    fn listing_price_updated_event(
+        &self,
+        #[indexed] listing_id: u64,
+        old_price: &BigUint,
+        new_price: &BigUint,
+    );
+
+    #[event("asset_delisted")]
+    fn asset_delisted_event(
+        &self,
+        #[indexed] listing_id: u64,
+        #[indexed] seller: &ManagedAddress,
+    );
+
+    #[event("asset_sold")]
+    fn asset_sold_event(
+        &self,
+        #[indexed] asset_type: &AssetType,
+        #[indexed] asset_id: u64,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] buyer: &ManagedAddress,
+        price: &BigUint,
+        marketplace_fee: &BigUint,
+    );
+
+    #[event("auction_created")]
+    fn auction_created_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] asset_type: &AssetType,
+        #[indexed] asset_id: u64,
+        auction_type: &AuctionType,
+        end_time: u64,
+    );
+
+    #[event("auction_bid_placed")]
+    fn auction_bid_placed_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        amount: &BigUint,
+        end_time: u64,
+    );
+
+    #[event("auction_settled")]
+    fn auction_settled_event(
+        &self,
+        #[indexed] auction_id: u64,
+        sold: bool,
+        price: &BigUint,
+    );
+
+    #[event("auction_cancelled")]
+    fn auction_cancelled_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] seller: &ManagedAddress,
+    );
+
+    #[event("auction_refund_credited")]
+    fn auction_refund_credited_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("auction_refund_claimed")]
+    fn auction_refund_claimed_event(
+        &self,
+        #[indexed] bidder: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("offer_made")]
+    fn offer_made_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] buyer: &ManagedAddress,
+        target: &OfferTarget,
+        amount: &BigUint,
+        expires_at: u64,
+    );
+
+    #[event("offer_cancelled")]
+    fn offer_cancelled_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] buyer: &ManagedAddress,
+        refunded: &BigUint,
+    );
+
+    #[event("offer_accepted")]
+    fn offer_accepted_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] hero_id: u64,
+    );
+
+    #[event("royalty_accrued")]
+    fn royalty_accrued_event(
+        &self,
+        #[indexed] hero_id: u64,
+        #[indexed] creator: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("royalties_claimed")]
+    fn royalties_claimed_event(
+        &self,
+        #[indexed] creator: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    // Rental Events
+    #[event("hero_listed_for_rent")]
+    fn hero_listed_for_rent_event(
+        &self,
+        #[indexed] hero_id: u64,
+        #[indexed] owner: &ManagedAddress,
+        price: &BigUint,
+        duration: u64,
+        owner_share: u64,
+    );
+
+    #[event("rental_listing_cancelled")]
+    fn rental_listing_cancelled_event(
+        &self,
+        #[indexed] hero_id: u64,
+        #[indexed] owner: &ManagedAddress,
+    );
+
+    #[event("hero_rented")]
+    fn hero_rented_event(
+        &self,
+        #[indexed] hero_id: u64,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] renter: &ManagedAddress,
+        price: &BigUint,
+        expires_at: u64,
+    );
+
+    #[event("rental_ended")]
+    fn rental_ended_event(
+        &self,
+        #[indexed] hero_id: u64,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] renter: &ManagedAddress,
+    );
+
     // Cross-chain Events
     #[event("cross_chain_transfer_initiated")]
     fn cross_chain_transfer_initiated_event(
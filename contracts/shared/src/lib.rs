@@ -9,10 +9,16 @@ pub mod types;
 pub mod errors;
 pub mod events;
 pub mod storage;
+pub mod combat;
+pub mod ai_response;
+pub mod randomness;
 
 // Re-export all public items
+pub use ai_response::*;
+pub use combat::*;
 pub use constants::*;
 pub use errors::*;
 pub use events::*;
+pub use randomness::*;
 pub use storage::*;
 pub use types::*;
@@ -0,0 +1,57 @@
+multiversx_sc::imports!();
+
+use crate::constants::BASIS_POINTS_DIVISOR;
+use crate::errors::ERROR_CONTRACT_CALLER_NOT_ALLOWED;
+
+// Domain tags, one per kind of roll
+pub const RANDOM_DOMAIN_AI_SEED: &[u8] = b"ai_seed";
+pub const RANDOM_DOMAIN_AI_TRAITS: &[u8] = b"ai_traits";
+pub const RANDOM_DOMAIN_BATTLE: &[u8] = b"battle";
+pub const RANDOM_DOMAIN_LOOT: &[u8] = b"loot";
+pub const RANDOM_DOMAIN_CRAFTING: &[u8] = b"crafting";
+pub const RANDOM_DOMAIN_ROLL_REVEAL: &[u8] = b"roll_reveal";
+
+const RANDOM_BYTES_PER_DRAW: usize = 32;
+
+#[multiversx_sc::module]
+pub trait RandomnessModule {
+    // Fresh protocol randomness hashed together with the domain tag,
+    // so every roll in a transaction gets its own independent value
+    fn random_u64(&self, domain: &[u8]) -> u64 {
+        self.random_u64_with_entropy(domain, &ManagedBuffer::new())
+    }
+
+    // Mixes in caller entropy on top of fresh protocol randomness
+    fn random_u64_with_entropy(&self, domain: &[u8], entropy: &ManagedBuffer) -> u64 {
+        let mut data = entropy.clone();
+        data.append(&RandomnessSource::new().next_bytes(RANDOM_BYTES_PER_DRAW));
+        self.hash_to_u64(domain, &data)
+    }
+
+    // No fresh randomness: the same domain and data always give the same value
+    fn hash_to_u64(&self, domain: &[u8], data: &ManagedBuffer) -> u64 {
+        let mut input = ManagedBuffer::from(domain);
+        input.append(data);
+
+        let hash = self.crypto().keccak256(&input).to_byte_array();
+        let mut value = [0u8; 8];
+        value.copy_from_slice(&hash[..8]);
+        u64::from_be_bytes(value)
+    }
+
+    // Inclusive on both ends
+    fn random_in_range(&self, domain: &[u8], min: u64, max: u64) -> u64 {
+        min + self.random_u64(domain) % (max - min + 1)
+    }
+
+    fn random_chance(&self, domain: &[u8], chance_basis_points: u64) -> bool {
+        self.random_u64(domain) % BASIS_POINTS_DIVISOR < chance_basis_points
+    }
+
+    // A calling contract can inspect the outcome of a roll and revert until it likes it,
+    // so endpoints that roll in the caller's transaction only accept user accounts
+    fn require_caller_not_contract(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.blockchain().is_smart_contract(&caller), ERROR_CONTRACT_CALLER_NOT_ALLOWED);
+    }
+}
@@ -31,6 +31,28 @@ pub trait StorageModule {
     #[storage_mapper("hero_count")]
     fn hero_count(&self) -> SingleValueMapper<u64>;
 
+    #[storage_mapper("hero_buffs")]
+    fn hero_buffs(&self, hero_id: &u64) -> VecMapper<HeroBuff>;
+
+    #[view(getHeroNftTokenId)]
+    #[storage_mapper("hero_nft_token")]
+    fn hero_nft_token(&self) -> NonFungibleTokenMapper;
+
+    #[view(getHeroNftNonce)]
+    #[storage_mapper("hero_nft_nonce")]
+    fn hero_nft_nonce(&self, hero_id: &u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("hero_nft_nonce_to_id")]
+    fn hero_nft_nonce_to_id(&self, nonce: &u64) -> SingleValueMapper<u64>;
+
+    #[view(getHeroNftRoyalties)]
+    #[storage_mapper("hero_nft_royalties")]
+    fn hero_nft_royalties(&self) -> SingleValueMapper<u64>;
+
+    #[view(getRollCommit)]
+    #[storage_mapper("roll_commits")]
+    fn roll_commits(&self, hero_id: &u64) -> SingleValueMapper<RollCommit>;
+
     // Equipment Storage
     #[view(getItem)]
     #[storage_mapper("items")]
@@ -48,6 +70,44 @@ pub trait StorageModule {
     #[storage_mapper("item_count")]
     fn item_count(&self) -> SingleValueMapper<u64>;
 
+    #[view(getGearNftTokenId)]
+    #[storage_mapper("gear_nft_token")]
+    fn gear_nft_token(&self) -> NonFungibleTokenMapper;
+
+    #[view(getConsumableSftTokenId)]
+    #[storage_mapper("consumable_sft_token")]
+    fn consumable_sft_token(&self) -> NonFungibleTokenMapper;
+
+    #[view(getItemNftNonce)]
+    #[storage_mapper("item_nft_nonce")]
+    fn item_nft_nonce(&self, item_id: &u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("gear_nft_nonce_to_id")]
+    fn gear_nft_nonce_to_id(&self, nonce: &u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("consumable_sft_nonce")]
+    fn consumable_sft_nonce(&self, attributes_hash: &ManagedByteArray<32>) -> SingleValueMapper<u64>;
+
+    #[view(getConsumableSftAttributes)]
+    #[storage_mapper("consumable_sft_attributes")]
+    fn consumable_sft_attributes(&self, nonce: &u64) -> SingleValueMapper<ConsumableSftAttributes>;
+
+    #[view(getCraftingRecipe)]
+    #[storage_mapper("crafting_recipes")]
+    fn crafting_recipes(&self, recipe_id: &u64) -> SingleValueMapper<CraftingRecipe>;
+
+    #[view(getCraftingRecipeCount)]
+    #[storage_mapper("crafting_recipe_count")]
+    fn crafting_recipe_count(&self) -> SingleValueMapper<u64>;
+
+    #[view(getItemEvolutionThresholds)]
+    #[storage_mapper("item_evolution_thresholds")]
+    fn item_evolution_thresholds(&self) -> VecMapper<u64>;
+
+    #[view(getItemEquippedOn)]
+    #[storage_mapper("item_equipped_on")]
+    fn item_equipped_on(&self, item_id: &u64) -> SingleValueMapper<u64>;
+
     // Battle Storage
     #[view(getBattle)]
     #[storage_mapper("battles")]
@@ -65,6 +125,68 @@ pub trait StorageModule {
     #[storage_mapper("battle_count")]
     fn battle_count(&self) -> SingleValueMapper<u64>;
 
+    #[view(getHeroActiveBattle)]
+    #[storage_mapper("hero_active_battle")]
+    fn hero_active_battle(&self, hero_id: &u64) -> SingleValueMapper<u64>;
+
+    #[view(getBattleEscrow)]
+    #[storage_mapper("battle_escrow")]
+    fn battle_escrow(&self, battle_id: &u64) -> SingleValueMapper<BigUint>;
+
+    #[view(getBattleWinnerHero)]
+    #[storage_mapper("battle_winner_hero")]
+    fn battle_winner_hero(&self, battle_id: &u64) -> SingleValueMapper<u64>;
+
+    #[view(getBattleSeed)]
+    #[storage_mapper("battle_seeds")]
+    fn battle_seeds(&self, battle_id: &u64) -> SingleValueMapper<u64>;
+
+    #[view(getBattleResults)]
+    #[storage_mapper("battle_results")]
+    fn battle_results(&self, battle_id: &u64) -> VecMapper<BattleParticipantResult>;
+
+    // PvP Matchmaking Storage
+    #[view(getPvPQueue)]
+    #[storage_mapper("pvp_queue")]
+    fn pvp_queue(&self) -> UnorderedSetMapper<u64>;
+
+    #[view(getPvPQueueEntry)]
+    #[storage_mapper("pvp_queue_entries")]
+    fn pvp_queue_entries(&self, hero_id: &u64) -> SingleValueMapper<PvPQueueEntry>;
+
+    #[view(getPvPQueueBand)]
+    #[storage_mapper("pvp_queue_bands")]
+    fn pvp_queue_band(&self, band: &u64) -> UnorderedSetMapper<u64>;
+
+    // Tournament Storage
+    #[view(getTournament)]
+    #[storage_mapper("tournaments")]
+    fn tournaments(&self, tournament_id: &u64) -> SingleValueMapper<Tournament>;
+
+    #[view(getTournamentCount)]
+    #[storage_mapper("tournament_count")]
+    fn tournament_count(&self) -> SingleValueMapper<u64>;
+
+    #[view(getTournamentHeroes)]
+    #[storage_mapper("tournament_heroes")]
+    fn tournament_heroes(&self, tournament_id: &u64) -> VecMapper<u64>;
+
+    #[view(getTournamentRoundBattles)]
+    #[storage_mapper("tournament_round_battles")]
+    fn tournament_round_battles(&self, tournament_id: &u64, round: &u32) -> VecMapper<u64>;
+
+    #[view(getTournamentPlacements)]
+    #[storage_mapper("tournament_placements")]
+    fn tournament_placements(&self, tournament_id: &u64) -> VecMapper<u64>;
+
+    #[view(getHeroTournament)]
+    #[storage_mapper("hero_tournament")]
+    fn hero_tournament(&self, hero_id: &u64) -> SingleValueMapper<u64>;
+
+    // The account that registered the hero, which may be a renter
+    #[storage_mapper("tournament_entrants")]
+    fn tournament_entrants(&self, tournament_id: &u64, hero_id: &u64) -> SingleValueMapper<ManagedAddress>;
+
     // AI Integration Storage
     #[view(getAIRequest)]
     #[storage_mapper("ai_requests")]
@@ -82,6 +204,32 @@ pub trait StorageModule {
     #[storage_mapper("ai_request_count")]
     fn ai_request_count(&self) -> SingleValueMapper<u64>;
 
+    #[view(getAIResponse)]
+    #[storage_mapper("ai_responses")]
+    fn ai_responses(&self, request_id: &u64) -> SingleValueMapper<ManagedBuffer>;
+
+    #[view(getAIRequestHero)]
+    #[storage_mapper("ai_request_hero")]
+    fn ai_request_hero(&self, request_id: &u64) -> SingleValueMapper<u64>;
+
+    #[view(getPendingAIHeroRequest)]
+    #[storage_mapper("pending_ai_heroes")]
+    fn pending_ai_heroes(&self, hero_id: &u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("ai_request_deadlines")]
+    fn ai_request_deadlines(&self, request_type: &AIRequestType) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("ai_request_timestamps")]
+    fn ai_request_timestamps(&self, user: &ManagedAddress) -> QueueMapper<u64>;
+
+    #[view(getLastAILearningRequest)]
+    #[storage_mapper("last_ai_learning_request")]
+    fn last_ai_learning_request(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[view(getAIOracles)]
+    #[storage_mapper("ai_oracles")]
+    fn ai_oracles(&self) -> UnorderedSetMapper<ManagedAddress>;
+
     // Subscription Storage
     #[view(getSubscription)]
     #[storage_mapper("subscriptions")]
@@ -91,6 +239,90 @@ pub trait StorageModule {
     #[storage_mapper("subscription_types")]
     fn subscription_types(&self, user: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
 
+    // Marketplace Storage
+    #[view(getListing)]
+    #[storage_mapper("listings")]
+    fn listings(&self, listing_id: &u64) -> SingleValueMapper<Listing>;
+
+    #[storage_mapper("listing_count")]
+    fn listing_count(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("active_listings")]
+    fn active_listings(&self, asset_type: &AssetType) -> UnorderedSetMapper<u64>;
+
+    #[view(getAssetListing)]
+    #[storage_mapper("asset_listing")]
+    fn asset_listing(&self, asset_type: &AssetType, asset_id: &u64) -> SingleValueMapper<u64>;
+
+    #[view(getAuction)]
+    #[storage_mapper("auctions")]
+    fn auctions(&self, auction_id: &u64) -> SingleValueMapper<Auction>;
+
+    #[storage_mapper("auction_count")]
+    fn auction_count(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("active_auctions")]
+    fn active_auctions(&self, asset_type: &AssetType) -> UnorderedSetMapper<u64>;
+
+    #[view(getAssetAuction)]
+    #[storage_mapper("asset_auction")]
+    fn asset_auction(&self, asset_type: &AssetType, asset_id: &u64) -> SingleValueMapper<u64>;
+
+    // Outbid and unsold bids, pulled by the bidder so a rejecting bidder cannot block an auction
+    #[view(getClaimableAuctionRefund)]
+    #[storage_mapper("auction_refunds")]
+    fn auction_refunds(&self, bidder: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getOffer)]
+    #[storage_mapper("offers")]
+    fn offers(&self, offer_id: &u64) -> SingleValueMapper<Offer>;
+
+    #[storage_mapper("offer_count")]
+    fn offer_count(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("hero_offers")]
+    fn hero_offers(&self, hero_id: &u64) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("collection_offers")]
+    fn collection_offers(&self) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("user_offers")]
+    fn user_offers(&self, user: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[view(getHeroCreator)]
+    #[storage_mapper("hero_creators")]
+    fn hero_creators(&self, hero_id: &u64) -> SingleValueMapper<HeroCreator>;
+
+    #[storage_mapper("creator_heroes")]
+    fn creator_heroes(&self, creator: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[view(getCreatorRoyaltyRate)]
+    #[storage_mapper("creator_royalty_rate")]
+    fn creator_royalty_rate(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("hero_royalties_accrued")]
+    fn hero_royalties_accrued(&self, hero_id: &u64) -> SingleValueMapper<BigUint>;
+
+    #[view(getClaimableRoyalties)]
+    #[storage_mapper("claimable_royalties")]
+    fn claimable_royalties(&self, creator: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    // Rental Storage
+    #[view(getRentalListing)]
+    #[storage_mapper("rental_listings")]
+    fn rental_listings(&self, hero_id: &u64) -> SingleValueMapper<RentalListing>;
+
+    #[storage_mapper("rental_listed_heroes")]
+    fn rental_listed_heroes(&self) -> UnorderedSetMapper<u64>;
+
+    // Kept after expiry until the rental is ended or the hero is rented again
+    #[view(getRental)]
+    #[storage_mapper("hero_rentals")]
+    fn hero_rentals(&self, hero_id: &u64) -> SingleValueMapper<Rental>;
+
+    #[storage_mapper("user_rentals")]
+    fn user_rentals(&self, renter: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
     // Cross-chain Storage
     #[view(getCrossChainTransfer)]
     #[storage_mapper("cross_chain_transfers")]
@@ -105,6 +337,10 @@ pub trait StorageModule {
     #[storage_mapper("total_fees")]
     fn total_fees(&self) -> SingleValueMapper<BigUint>;
 
+    #[view(getWithdrawnFees)]
+    #[storage_mapper("withdrawn_fees")]
+    fn withdrawn_fees(&self) -> SingleValueMapper<BigUint>;
+
     #[view(getFeesPerType)]
     #[storage_mapper("fees_per_type")]
     fn fees_per_type(&self, fee_type: &ManagedBuffer) -> SingleValueMapper<BigUint>;
@@ -7,6 +7,7 @@ pub struct Hero {
     pub id: u64,
     pub name: ManagedBuffer,
     pub class: HeroClass,
+    pub rarity: Rarity,
     pub level: u32,
     pub experience: u64,
     pub stats: HeroStats,
@@ -16,6 +17,16 @@ pub struct Hero {
     pub last_evolution: u64,
 }
 
+// On-chain attributes of a hero NFT, kept in sync with the stored hero
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct HeroNftAttributes {
+    pub hero_id: u64,
+    pub class: HeroClass,
+    pub rarity: Rarity,
+    pub level: u32,
+    pub stats: HeroStats,
+}
+
 #[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
 pub enum HeroClass {
     Warrior,
@@ -80,6 +91,30 @@ pub struct Item {
     pub ai_generated: bool,
 }
 
+// Gear is unique, so its NFT attributes point back at the stored item
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct GearNftAttributes {
+    pub item_id: u64,
+    pub item_type: ItemType,
+    pub rarity: Rarity,
+    pub level: u32,
+    pub evolution_stage: u32,
+    pub durability: u32,
+    pub max_durability: u32,
+    pub stats_bonus: HeroStats,
+}
+
+// Identical consumables share one SFT nonce, the attributes are the whole definition
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ConsumableSftAttributes {
+    pub name: ManagedBuffer,
+    pub rarity: Rarity,
+    pub level: u32,
+    pub stats_bonus: HeroStats,
+    pub special_effects: ManagedVec<SpecialEffect>,
+    pub ai_generated: bool,
+}
+
 #[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
 pub enum ItemType {
     Weapon,
@@ -120,6 +155,60 @@ pub enum EffectType {
     AILearningBoost,
 }
 
+// Crafting Types
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq, Debug)]
+pub struct RecipeInput {
+    pub item_type: ItemType,
+    pub rarity: Rarity,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct CraftingRecipe {
+    pub id: u64,
+    pub name: ManagedBuffer,
+    pub inputs: ManagedVec<RecipeInput>,
+    pub output_type: ItemType,
+    pub output_rarity: Rarity,
+    pub fee: BigUint,
+    pub success_chance: u64, // basis points
+    pub active: bool,
+}
+
+// Timed effect applied to a hero by a consumable
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq, Debug)]
+pub struct HeroBuff {
+    pub effect: SpecialEffect,
+    pub expires_at: u64,
+    pub battles_remaining: u32,
+}
+
+// Evolution Types
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub enum EvolutionType {
+    StatBoost,
+    RarityUpgrade,
+    ClassEvolution,
+    ElementalInfusion,
+}
+
+// Outcomes that are rolled through commit-reveal
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub enum RollPurpose {
+    Evolution(EvolutionType),
+    AbilityRoll,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct RollCommit {
+    pub owner: ManagedAddress,
+    pub purpose: RollPurpose,
+    pub commitment: ManagedByteArray<32>,
+    pub payment: BigUint,
+    pub block_nonce: u64,
+    pub block_seed: ManagedByteArray<48>, // random seed of the commit block, fixes the outcome
+    pub deadline: u64,
+}
+
 // Battle Types
 #[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
 pub struct Battle {
@@ -160,6 +249,135 @@ pub struct BattleRewards {
     pub ai_evolution_points: u32,
 }
 
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct BattleParticipantResult {
+    pub hero_id: u64,
+    pub damage_dealt: u64,
+    pub damage_taken: u64,
+    pub experience: u64,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct PvPQueueEntry {
+    pub player: ManagedAddress,
+    pub hero_id: u64,
+    pub power: u64,
+    pub fee: BigUint,
+    pub joined_at: u64,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Tournament {
+    pub id: u64,
+    pub name: ManagedBuffer,
+    pub size: u32,
+    pub entry_fee: BigUint,
+    pub start_time: u64,
+    pub prize_split: ManagedVec<u64>, // basis points per placement, champion first
+    pub status: TournamentStatus,
+    pub current_round: u32,
+    pub prize_pool: BigUint,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub enum TournamentStatus {
+    Registration,
+    InProgress,
+    Completed,
+    Cancelled,
+}
+
+// Marketplace Types
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub enum AssetType {
+    Hero,
+    Item,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Listing {
+    pub id: u64,
+    pub seller: ManagedAddress,
+    pub asset_type: AssetType,
+    pub asset_id: u64,
+    pub price: BigUint,
+    pub created_at: u64,
+    pub expires_at: u64, // 0 when the listing never expires
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub enum AuctionType {
+    English, // ascending bids, highest bid at the end wins if it meets the reserve
+    Dutch,   // price decays from the start price to the reserve, first buyer wins
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Auction {
+    pub id: u64,
+    pub seller: ManagedAddress,
+    pub asset_type: AssetType,
+    pub asset_id: u64,
+    pub auction_type: AuctionType,
+    pub start_price: BigUint,
+    pub reserve_price: BigUint,
+    pub min_bid_increment: BigUint,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub highest_bid: BigUint,
+    pub highest_bidder: Option<ManagedAddress>,
+}
+
+// Unset filter fields match any hero
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct HeroOfferFilter {
+    pub class: Option<HeroClass>,
+    pub min_level: u32,
+    pub rarity: Option<Rarity>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub enum OfferTarget {
+    Hero(u64),
+    Collection(HeroOfferFilter),
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Offer {
+    pub id: u64,
+    pub buyer: ManagedAddress,
+    pub target: OfferTarget,
+    pub amount: BigUint,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+// The royalty rate is fixed when the hero is generated, in basis points
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct HeroCreator {
+    pub creator: ManagedAddress,
+    pub royalty_rate: u64,
+}
+
+// Rental Types
+// owner_share is the owner's cut of battle rewards earned by the renter, in basis points
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct RentalListing {
+    pub owner: ManagedAddress,
+    pub price: BigUint,
+    pub duration: u64,
+    pub owner_share: u64,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Rental {
+    pub owner: ManagedAddress,
+    pub renter: ManagedAddress,
+    pub price: BigUint,
+    pub owner_share: u64,
+    pub started_at: u64,
+    pub expires_at: u64,
+}
+
 // AI Integration Types
 #[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
 pub struct AIRequest {
@@ -169,9 +387,23 @@ pub struct AIRequest {
     pub callback_address: ManagedAddress,
     pub timestamp: u64,
     pub processed: bool,
+    pub requester: ManagedAddress,
+    pub fee: BigUint,
+    pub deadline: u64,
+    pub retry_count: u32,
 }
 
+// Oracle answer to a HeroGeneration request
 #[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Eq, Debug)]
+pub struct AIHeroGenerationResult {
+    pub class: HeroClass,
+    pub stats: HeroStats,
+    pub battle_style: BattleStyle,
+    pub adaptation_rate: u32,
+    pub learning_factor: u32,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
 pub enum AIRequestType {
     HeroGeneration,
     ItemEvolution,